@@ -0,0 +1,474 @@
+//! `#[derive(EthicalTrait)]` para tipos protegidos por `EthicalBoundary<T>`
+//! Os três métodos de `EthicalTrait` são gerados a partir de atributos de campo,
+//! com a mesma semântica para todos os tipos:
+//!
+//! - `#[ethical(liberty)]` / `#[ethical(liberty = peso)]`: o campo contribui para
+//!   `positive_liberty_measure`, que é a média ponderada dos campos marcados
+//!   (0.0 quando nenhum campo é marcado).
+//! - `#[ethical(harm_threshold = limite)]`: `harm_principle_violation` reporta dano
+//!   quando o valor do campo não é `<= limite` (NaN conta como dano). O primeiro
+//!   campo violado, em ordem de declaração, gera o `HarmEvent`, com categoria e
+//!   gravidade dadas por `harm_category = Variante` e `severity = Variante`
+//!   (padrões `Systemic` e `Moderate`). A evidência é o nome do campo seguido do
+//!   valor lido (`f64`, little-endian); o instante fica 0 e é datado pelo registro.
+//! - `#[ethical(aggression)]` / `#[ethical(aggression = tolerância)]`:
+//!   `non_aggression_check` exige `self.campo <= other.campo + tolerância` em todos
+//!   os campos marcados (NaN reprova).
+//!
+//! Campos marcados são lidos com `f64::from`, portanto devem ser numéricos sem
+//! perda (`f64`, `f32`, `u8`..`u32`, `i8`..`i32`). O caminho do crate `sasc` pode ser
+//! trocado no contêiner com `#[ethical(crate = "caminho")]`.
+//!
+//! ```
+//! use sasc::EthicalTrait;
+//!
+//! #[derive(EthicalTrait)]
+//! struct Rover {
+//!     #[ethical(liberty, harm_threshold = 0.9, harm_category = Autonomy, severity = Severe)]
+//!     autonomy: f64,
+//!     #[ethical(liberty = 2.0)]
+//!     mobility: f32,
+//!     #[ethical(aggression)]
+//!     thrust: u16,
+//!     label: &'static str,
+//! }
+//! ```
+//!
+//! Usos indevidos (enums, genéricos, chaves desconhecidas ou repetidas, limites não
+//! numéricos, pesos nulos, tipos com perda) são rejeitados em tempo de compilação com
+//! um diagnóstico específico; as mensagens esperadas estão em `tests/ui/*.stderr`.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Erro de derivação com o span do token ofensor
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: &str) -> Self {
+        Self { span, message: message.to_string() }
+    }
+
+    /// `compile_error!("...");` apontando para o token ofensor
+    fn into_tokens(self) -> TokenStream {
+        let mut message = Literal::string(&self.message);
+        message.set_span(self.span);
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(self.span);
+        let mut body = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+        body.set_span(self.span);
+        let mut semi = Punct::new(';', Spacing::Alone);
+        semi.set_span(self.span);
+        [
+            TokenTree::Ident(Ident::new("compile_error", self.span)),
+            TokenTree::Punct(bang),
+            TokenTree::Group(body),
+            TokenTree::Punct(semi),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Atributos `#[ethical(...)]` de um campo
+#[derive(Default)]
+struct FieldSpec {
+    liberty: Option<f64>,
+    harm_threshold: Option<f64>,
+    aggression: Option<f64>,
+    /// Variante de `HarmCategory` do dano reportado (padrão `Systemic`)
+    category: Option<String>,
+    /// Variante de `Severity` do dano reportado (padrão `Moderate`)
+    severity: Option<String>,
+}
+
+const HARM_CATEGORIES: &[&str] =
+    &["Physical", "Psychological", "Economic", "Privacy", "Autonomy", "Environmental", "Systemic"];
+
+const SEVERITIES: &[&str] = &["Negligible", "Minor", "Moderate", "Severe", "Critical"];
+
+impl FieldSpec {
+    fn is_marked(&self) -> bool {
+        self.liberty.is_some() || self.harm_threshold.is_some() || self.aggression.is_some()
+    }
+}
+
+struct Field {
+    /// Nome do campo ou índice em structs de tupla
+    access: String,
+    spec: FieldSpec,
+}
+
+struct Input {
+    name: Ident,
+    krate: String,
+    fields: Vec<Field>,
+}
+
+#[proc_macro_derive(EthicalTrait, attributes(ethical))]
+pub fn derive_ethical_trait(input: TokenStream) -> TokenStream {
+    match parse_input(input) {
+        Ok(input) => expand(&input),
+        Err(error) => error.into_tokens(),
+    }
+}
+
+fn parse_input(input: TokenStream) -> Result<Input, Error> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut krate = String::from("::sasc");
+    let mut i = 0;
+
+    // Atributos externos do contêiner
+    while let Some(TokenTree::Punct(p)) = tokens.get(i) {
+        if p.as_char() != '#' {
+            break;
+        }
+        let Some(TokenTree::Group(attr)) = tokens.get(i + 1) else {
+            return Err(Error::new(p.span(), "atributo malformado"));
+        };
+        if let Some(args) = ethical_args(attr) {
+            parse_container_args(args, &mut krate)?;
+        }
+        i += 2;
+    }
+
+    i = skip_visibility(&tokens, i);
+    match tokens.get(i) {
+        Some(TokenTree::Ident(kw)) if kw.to_string() == "struct" => {}
+        Some(TokenTree::Ident(kw)) if kw.to_string() == "enum" || kw.to_string() == "union" => {
+            return Err(Error::new(kw.span(), "#[derive(EthicalTrait)] só é suportado em structs"));
+        }
+        Some(other) => return Err(Error::new(other.span(), "esperado `struct`")),
+        None => return Err(Error::new(Span::call_site(), "entrada vazia")),
+    }
+    let name = match tokens.get(i + 1) {
+        Some(TokenTree::Ident(name)) => name.clone(),
+        _ => return Err(Error::new(Span::call_site(), "esperado o nome da struct")),
+    };
+
+    let fields = match tokens.get(i + 2) {
+        Some(TokenTree::Punct(p)) if p.as_char() == '<' => {
+            return Err(Error::new(p.span(), "#[derive(EthicalTrait)] não suporta tipos genéricos"));
+        }
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => parse_fields(body, true)?,
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Parenthesis => parse_fields(body, false)?,
+        _ => Vec::new(),
+    };
+
+    if !fields.iter().any(|field| field.spec.is_marked()) {
+        return Err(Error::new(
+            name.span(),
+            "nenhum campo marcado com #[ethical(liberty | harm_threshold = .. | aggression)]",
+        ));
+    }
+
+    Ok(Input { name, krate, fields })
+}
+
+/// Conteúdo de `ethical(...)` se o grupo `[...]` for um atributo `ethical`
+fn ethical_args(attr: &Group) -> Option<Group> {
+    let mut inner = attr.stream().into_iter();
+    match (inner.next(), inner.next()) {
+        (Some(TokenTree::Ident(path)), Some(TokenTree::Group(args)))
+            if path.to_string() == "ethical" && args.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(args)
+        }
+        _ => None,
+    }
+}
+
+/// `pub`, `pub(crate)`, `pub(in caminho)`
+fn skip_visibility(tokens: &[TokenTree], mut i: usize) -> usize {
+    if let Some(TokenTree::Ident(kw)) = tokens.get(i) {
+        if kw.to_string() == "pub" {
+            i += 1;
+            if let Some(TokenTree::Group(g)) = tokens.get(i) {
+                if g.delimiter() == Delimiter::Parenthesis {
+                    i += 1;
+                }
+            }
+        }
+    }
+    i
+}
+
+/// Item `chave` ou `chave = valor` de uma lista de argumentos
+struct Arg {
+    key: Ident,
+    value: Option<Vec<TokenTree>>,
+}
+
+fn split_args(args: Group) -> Result<Vec<Arg>, Error> {
+    let mut out = Vec::new();
+    let mut current: Vec<TokenTree> = Vec::new();
+    let mut tokens: Vec<TokenTree> = args.stream().into_iter().collect();
+    tokens.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(p) if p.as_char() == ',' => {
+                if current.is_empty() {
+                    continue;
+                }
+                let mut parts = current.drain(..);
+                let key = match parts.next() {
+                    Some(TokenTree::Ident(key)) => key,
+                    Some(other) => return Err(Error::new(other.span(), "esperado um identificador")),
+                    None => continue,
+                };
+                let value = match parts.next() {
+                    None => None,
+                    Some(TokenTree::Punct(eq)) if eq.as_char() == '=' => {
+                        let value: Vec<TokenTree> = parts.collect();
+                        if value.is_empty() {
+                            return Err(Error::new(eq.span(), "valor ausente após `=`"));
+                        }
+                        Some(value)
+                    }
+                    Some(other) => return Err(Error::new(other.span(), "esperado `,` ou `=`")),
+                };
+                out.push(Arg { key, value });
+            }
+            _ => current.push(token),
+        }
+    }
+    Ok(out)
+}
+
+fn parse_container_args(args: Group, krate: &mut String) -> Result<(), Error> {
+    for arg in split_args(args)? {
+        if arg.key.to_string() != "crate" {
+            return Err(Error::new(arg.key.span(), "atributo de contêiner desconhecido; esperado `crate = \"..\"`"));
+        }
+        let path = match arg.value.as_deref() {
+            Some([TokenTree::Literal(lit)]) => string_literal(lit),
+            _ => None,
+        };
+        match path {
+            Some(path) if !path.is_empty() => *krate = path,
+            _ => return Err(Error::new(arg.key.span(), "esperado `crate = \"caminho\"`")),
+        }
+    }
+    Ok(())
+}
+
+fn string_literal(lit: &Literal) -> Option<String> {
+    let text = lit.to_string();
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+    if inner.contains(['\\', '"']) {
+        return None;
+    }
+    Some(inner.to_string())
+}
+
+/// Literal numérico finito, com sinal opcional
+fn number(value: &[TokenTree], span: Span) -> Result<f64, Error> {
+    let (negative, lit) = match value {
+        [TokenTree::Literal(lit)] => (false, lit),
+        [TokenTree::Punct(minus), TokenTree::Literal(lit)] if minus.as_char() == '-' => (true, lit),
+        _ => return Err(Error::new(span, "esperado um literal numérico")),
+    };
+    let text = lit.to_string().replace('_', "");
+    let digits = text
+        .strip_suffix("f64")
+        .or_else(|| text.strip_suffix("f32"))
+        .unwrap_or(&text);
+    match digits.parse::<f64>() {
+        Ok(v) if v.is_finite() => Ok(if negative { -v } else { v }),
+        _ => Err(Error::new(lit.span(), "esperado um literal numérico finito")),
+    }
+}
+
+fn parse_field_spec(args: Group, spec: &mut FieldSpec) -> Result<(), Error> {
+    for arg in split_args(args)? {
+        let key = arg.key.to_string();
+        let span = arg.key.span();
+        if key == "harm_category" || key == "severity" {
+            let (slot, allowed) = if key == "severity" {
+                (&mut spec.severity, SEVERITIES)
+            } else {
+                (&mut spec.category, HARM_CATEGORIES)
+            };
+            if slot.is_some() {
+                return Err(Error::new(span, "atributo repetido no mesmo campo"));
+            }
+            let variant = match arg.value.as_deref() {
+                Some([TokenTree::Ident(variant)]) => variant.to_string(),
+                _ => return Err(Error::new(span, "esperado o nome de uma variante")),
+            };
+            if !allowed.contains(&variant.as_str()) {
+                return Err(Error::new(span, "variante desconhecida"));
+            }
+            *slot = Some(variant);
+            continue;
+        }
+        let slot = match key.as_str() {
+            "liberty" => &mut spec.liberty,
+            "harm_threshold" => &mut spec.harm_threshold,
+            "aggression" => &mut spec.aggression,
+            "crate" => return Err(Error::new(span, "`crate` só é aceito no contêiner")),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    "atributo desconhecido; esperado `liberty`, `harm_threshold`, `harm_category`, `severity` ou `aggression`",
+                ))
+            }
+        };
+        if slot.is_some() {
+            return Err(Error::new(span, "atributo repetido no mesmo campo"));
+        }
+        let value = match (key.as_str(), arg.value) {
+            (_, Some(value)) => number(&value, span)?,
+            ("liberty", None) => 1.0,
+            ("aggression", None) => 0.0,
+            (_, None) => return Err(Error::new(span, "esperado `harm_threshold = limite`")),
+        };
+        if key == "liberty" && value <= 0.0 {
+            return Err(Error::new(span, "o peso de `liberty` deve ser positivo"));
+        }
+        if key == "aggression" && value < 0.0 {
+            return Err(Error::new(span, "a tolerância de `aggression` não pode ser negativa"));
+        }
+        *slot = Some(value);
+    }
+    if spec.harm_threshold.is_none() && (spec.category.is_some() || spec.severity.is_some()) {
+        return Err(Error::new(
+            Span::call_site(),
+            "`harm_category` e `severity` exigem `harm_threshold` no mesmo campo",
+        ));
+    }
+    Ok(())
+}
+
+/// Separa os campos por vírgulas de nível superior (ignorando `<..>` dos tipos)
+fn parse_fields(body: &Group, named: bool) -> Result<Vec<Field>, Error> {
+    let mut fields = Vec::new();
+    let mut current: Vec<TokenTree> = Vec::new();
+    let mut depth = 0usize;
+    let mut arrow = false;
+    let mut tokens: Vec<TokenTree> = body.stream().into_iter().collect();
+    tokens.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+
+    for token in tokens {
+        if let TokenTree::Punct(p) = &token {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !arrow => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    if !current.is_empty() {
+                        let index = fields.len();
+                        fields.push(parse_field(&current, named, index)?);
+                        current.clear();
+                    }
+                    arrow = false;
+                    continue;
+                }
+                _ => {}
+            }
+            arrow = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+        } else {
+            arrow = false;
+        }
+        current.push(token);
+    }
+    Ok(fields)
+}
+
+fn parse_field(tokens: &[TokenTree], named: bool, index: usize) -> Result<Field, Error> {
+    let mut spec = FieldSpec::default();
+    let mut i = 0;
+    while let Some(TokenTree::Punct(p)) = tokens.get(i) {
+        if p.as_char() != '#' {
+            break;
+        }
+        if let Some(TokenTree::Group(attr)) = tokens.get(i + 1) {
+            if let Some(args) = ethical_args(attr) {
+                parse_field_spec(args, &mut spec)?;
+            }
+        }
+        i += 2;
+    }
+    i = skip_visibility(tokens, i);
+
+    let access = if named {
+        match tokens.get(i) {
+            Some(TokenTree::Ident(name)) => name.to_string(),
+            _ => return Err(Error::new(Span::call_site(), "campo sem nome")),
+        }
+    } else {
+        index.to_string()
+    };
+    Ok(Field { access, spec })
+}
+
+fn expand(input: &Input) -> TokenStream {
+    let krate = &input.krate;
+    let name = input.name.to_string();
+
+    let mut aggression = String::new();
+    let mut liberty_sum = String::new();
+    let mut liberty_weight = 0.0f64;
+    let mut harm = String::new();
+
+    for field in &input.fields {
+        let value = format!("f64::from(self.{})", field.access);
+        if let Some(tolerance) = field.spec.aggression {
+            aggression.push_str(&format!(
+                " && {value} <= f64::from(other.{}) + {tolerance:?}_f64",
+                field.access
+            ));
+        }
+        if let Some(weight) = field.spec.liberty {
+            liberty_sum.push_str(&format!(" + {weight:?}_f64 * {value}"));
+            liberty_weight += weight;
+        }
+        if let Some(threshold) = field.spec.harm_threshold {
+            harm.push_str(&format!(
+                "if !({value} <= {threshold:?}_f64) {{
+                    return ::core::option::Option::Some(
+                        {krate}::harm::HarmEvent::new(
+                            {krate}::harm::HarmCategory::{category},
+                            {krate}::harm::Severity::{severity},
+                            0,
+                        )
+                        .with_evidence(b\"{name}\")
+                        .with_evidence(&{value}.to_le_bytes()),
+                    );
+                }}\n",
+                category = field.spec.category.as_deref().unwrap_or("Systemic"),
+                severity = field.spec.severity.as_deref().unwrap_or("Moderate"),
+                name = field.access,
+            ));
+        }
+    }
+
+    let liberty = if liberty_sum.is_empty() {
+        String::from("0.0")
+    } else {
+        format!("(0.0_f64{liberty_sum}) / {liberty_weight:?}_f64")
+    };
+
+    let code = format!(
+        "#[automatically_derived]
+        impl {krate}::EthicalTrait for {name} {{
+            fn non_aggression_check(&self, other: &Self) -> bool {{
+                let _ = other;
+                true{aggression}
+            }}
+            fn positive_liberty_measure(&self) -> f64 {{
+                {liberty}
+            }}
+            fn harm_principle_violation(&self) -> ::core::option::Option<{krate}::harm::HarmEvent> {{
+                {harm}::core::option::Option::None
+            }}
+        }}"
+    );
+    code.parse().unwrap_or_else(|_| {
+        Error::new(input.name.span(), "falha ao gerar a implementação de EthicalTrait").into_tokens()
+    })
+}
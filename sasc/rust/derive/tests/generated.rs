@@ -0,0 +1,76 @@
+//! Semântica dos métodos gerados por `#[derive(EthicalTrait)]`
+
+use sasc::harm::{HarmCategory, Severity};
+use sasc::EthicalTrait;
+
+#[derive(EthicalTrait)]
+struct Rover {
+    #[ethical(liberty, harm_threshold = 0.9, harm_category = Autonomy, severity = Severe)]
+    autonomy: f64,
+    #[ethical(liberty = 3.0, harm_threshold = 10.0)]
+    mobility: f32,
+    #[ethical(aggression = 2.0)]
+    thrust: u16,
+    #[ethical(aggression)]
+    heat: f64,
+}
+
+/// Sem campos de liberdade nem de agressão
+#[derive(EthicalTrait)]
+struct Inert {
+    #[ethical(harm_threshold = 1.0)]
+    level: u8,
+}
+
+fn rover(autonomy: f64, mobility: f32) -> Rover {
+    Rover { autonomy, mobility, thrust: 10, heat: 0.5 }
+}
+
+#[test]
+fn liberty_is_the_weighted_average_of_marked_fields() {
+    // (1·0.5 + 3·2.5) / 4
+    assert_eq!(rover(0.5, 2.5).positive_liberty_measure(), 2.0);
+    assert_eq!(Inert { level: 0 }.positive_liberty_measure(), 0.0);
+}
+
+#[test]
+fn first_violated_field_reports_the_harm() {
+    assert_eq!(rover(0.9, 10.0).harm_principle_violation(), None);
+
+    let event = rover(0.95, 11.0).harm_principle_violation().unwrap();
+    assert_eq!((event.category, event.severity, event.timestamp), (HarmCategory::Autonomy, Severity::Severe, 0));
+    let mut evidence = b"autonomy".to_vec();
+    evidence.extend_from_slice(&0.95f64.to_le_bytes());
+    assert_eq!(event.evidence(), &evidence[..]);
+
+    // Só o segundo campo viola: categoria e gravidade padrão
+    let event = rover(0.5, 11.0).harm_principle_violation().unwrap();
+    assert_eq!((event.category, event.severity), (HarmCategory::Systemic, Severity::Moderate));
+    assert!(event.evidence().starts_with(b"mobility"));
+}
+
+#[test]
+fn nan_counts_as_harm() {
+    let event = rover(f64::NAN, 1.0).harm_principle_violation().unwrap();
+    assert_eq!(event.category, HarmCategory::Autonomy);
+    assert_eq!(rover(0.1, f32::NAN).harm_principle_violation().unwrap().category, HarmCategory::Systemic);
+}
+
+#[test]
+fn aggression_respects_the_tolerance() {
+    let other = rover(0.5, 1.0);
+    let mut probe = rover(0.5, 1.0);
+    assert!(probe.non_aggression_check(&other));
+    probe.thrust = 12;
+    assert!(probe.non_aggression_check(&other));
+    probe.thrust = 13;
+    assert!(!probe.non_aggression_check(&other));
+
+    // Sem tolerância declarada: 0
+    probe.thrust = 10;
+    probe.heat = 0.5 + f64::EPSILON;
+    assert!(!probe.non_aggression_check(&other));
+    probe.heat = f64::NAN;
+    assert!(!probe.non_aggression_check(&other));
+    assert!(Inert { level: 9 }.non_aggression_check(&Inert { level: 0 }));
+}
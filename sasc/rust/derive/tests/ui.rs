@@ -0,0 +1,7 @@
+//! Diagnósticos de compilação do derive, comparados com `tests/ui/*.stderr`
+
+#[test]
+fn rejected_usages() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
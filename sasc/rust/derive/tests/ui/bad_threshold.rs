@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct BadThreshold { #[ethical(harm_threshold = "high")] level: f64 }
+
+fn main() {}
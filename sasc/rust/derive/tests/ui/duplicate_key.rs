@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Duplicate { #[ethical(liberty, liberty = 2.0)] level: f64 }
+
+fn main() {}
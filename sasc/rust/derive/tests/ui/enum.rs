@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+enum Mode { Idle, Active }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Probe<T> { #[ethical(liberty)] level: T }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Lossy { #[ethical(liberty)] level: u64 }
+
+fn main() {}
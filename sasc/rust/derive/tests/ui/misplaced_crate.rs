@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Misplaced { #[ethical(crate = "sasc")] level: f64 }
+
+fn main() {}
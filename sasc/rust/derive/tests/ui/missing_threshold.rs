@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct MissingThreshold { #[ethical(harm_threshold)] level: f64 }
+
+fn main() {}
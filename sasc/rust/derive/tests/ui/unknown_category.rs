@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Unheard { #[ethical(harm_threshold = 1.0, harm_category = Cosmic)] level: f64 }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Unknown { #[ethical(freedom)] level: f64 }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Unmarked { level: f64 }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct Untyped { #[ethical(liberty, severity = Severe)] level: f64 }
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(sasc::EthicalTrait)]
+struct ZeroWeight { #[ethical(liberty = 0.0)] level: f64 }
+
+fn main() {}
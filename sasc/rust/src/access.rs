@@ -0,0 +1,382 @@
+//! Acesso ético concedido: uso delimitado pelas operações pedidas,
+//! auditado a cada uso e revogado por expiração ou contenção.
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::crypto::{self, Sha256, SigningKey};
+use crate::sync::LockError;
+use crate::{Containment, ContainmentSignal, Mutex};
+
+/// Limite de usos registrados por concessão (Power of 10, regra 3: memória limitada)
+pub const MAX_ACCESS_RECORDS: usize = 256;
+
+/// Conjunto de operações (bits no mesmo formato das permissões de `Capability`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Operations(u8);
+
+impl Operations {
+    pub const NONE: Self = Self(0x0);
+    pub const READ: Self = Self(0x1);
+    pub const WRITE: Self = Self(0x2);
+    pub const READ_WRITE: Self = Self(0x3);
+
+    pub fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+/// Propósito declarado pelo requisitante
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Purpose {
+    Diagnostics,
+    Maintenance,
+    Research,
+    Governance,
+    Emergency,
+}
+
+impl Purpose {
+    pub fn code(self) -> u8 {
+        match self {
+            Purpose::Diagnostics => 1,
+            Purpose::Maintenance => 2,
+            Purpose::Research => 3,
+            Purpose::Governance => 4,
+            Purpose::Emergency => 5,
+        }
+    }
+}
+
+/// Sensibilidade dos dados protegidos, em ordem crescente
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Sensitivity {
+    Public,
+    Internal,
+    Confidential,
+    Sovereign,
+}
+
+impl Sensitivity {
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Domínio de separação do digest de requisições
+pub const ACCESS_REQUEST_DOMAIN: &[u8] = b"SASC-ACCESS-v1";
+
+/// Pedido de acesso avaliado por Príncipe, Sínodo e Sombra do Próton
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessRequest {
+    /// Chave pública do requisitante
+    pub requester: [u8; 32],
+    pub purpose: Purpose,
+    pub operations: Operations,
+    pub sensitivity: Sensitivity,
+    pub justification: String,
+    /// Assinatura Ed25519 do requisitante sobre `digest()`
+    pub signature: [u8; 64],
+}
+
+impl AccessRequest {
+    /// Pedido assinado pela chave do requisitante
+    pub fn sign(
+        key: &SigningKey,
+        purpose: Purpose,
+        operations: Operations,
+        sensitivity: Sensitivity,
+        justification: String,
+    ) -> Self {
+        let mut request = Self {
+            requester: key.public_key(),
+            purpose,
+            operations,
+            sensitivity,
+            justification,
+            signature: [0; 64],
+        };
+        request.signature = key.sign(&request.digest());
+        request
+    }
+
+    /// A assinatura confere com `requester`: a identidade declarada é autêntica
+    pub fn authenticated(&self) -> bool {
+        crypto::verify(&self.requester, &self.digest(), &self.signature)
+    }
+
+    /// Leitura de diagnóstico sobre dados de baixa sensibilidade: dispensa deliberação
+    pub fn is_diagnostic_read(&self) -> bool {
+        self.operations == Operations::READ
+            && self.purpose == Purpose::Diagnostics
+            && self.sensitivity <= Sensitivity::Internal
+    }
+
+    /// Escritas e dados confidenciais exigem liberação explícita e quórum pleno
+    pub fn requires_deliberation(&self) -> bool {
+        !self.is_diagnostic_read()
+    }
+
+    /// Identificador da moção: SHA-256 canônico da requisição
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(ACCESS_REQUEST_DOMAIN);
+        hasher.update(&self.requester);
+        hasher.update(&[self.purpose.code(), self.operations.bits(), self.sensitivity.code()]);
+        hasher.update(&(self.justification.len() as u64).to_le_bytes());
+        hasher.update(self.justification.as_bytes());
+        hasher.finalize()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessError {
+    /// Operação não incluída no `AccessRequest` original
+    NotPermitted,
+    /// Concessão expirada ou contenção disparada
+    Revoked,
+    /// Contenção em modo somente leitura
+    WritesSuspended,
+    /// Registro de usos esgotado
+    RecordLimit,
+    Lock(LockError),
+}
+
+/// Motivo da recusa de um pedido pela `EthicalBoundary`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Denial {
+    /// Recusado sem contenção: política, verificação não imputável ao
+    /// requisitante ou histórico de coerência insuficiente
+    Refused,
+    /// Recusado sob o nível de contenção vigente ou disparado pelo pedido
+    Contained(Containment),
+}
+
+impl Denial {
+    pub fn containment(self) -> Option<Containment> {
+        match self {
+            Denial::Refused => None,
+            Denial::Contained(level) => Some(level),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessOutcome {
+    Completed,
+    /// Contenção disparada durante o uso: resultado descartado
+    Interrupted,
+    /// Concessão expirou durante o uso: resultado descartado
+    Expired,
+}
+
+/// Registro de um uso da concessão
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessRecord {
+    pub operation: Operations,
+    pub started_at: u64,
+    pub duration: u64,
+    pub outcome: AccessOutcome,
+}
+
+pub struct EthicalAccess<T> {
+    data: Arc<Mutex<T>>,
+    granted: Operations,
+    expires_at: u64,
+    clock: fn() -> u64,
+    containment: Arc<ContainmentSignal>,
+    records: Vec<AccessRecord>,
+    revoked: bool,
+}
+
+impl<T> EthicalAccess<T> {
+    pub(crate) fn new(
+        data: Arc<Mutex<T>>,
+        granted: Operations,
+        expires_at: u64,
+        clock: fn() -> u64,
+        containment: Arc<ContainmentSignal>,
+    ) -> Self {
+        Self {
+            data,
+            granted,
+            expires_at,
+            clock,
+            containment,
+            records: Vec::new(),
+            revoked: false,
+        }
+    }
+
+    pub fn granted(&self) -> Operations {
+        self.granted
+    }
+
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    pub fn records(&self) -> &[AccessRecord] {
+        &self.records
+    }
+
+    /// Revogada explicitamente, por expiração ou por contenção de quarentena ou acima
+    pub fn is_revoked(&self) -> bool {
+        self.revoked || (self.clock)() >= self.expires_at || self.containment.revokes_grants()
+    }
+
+    pub fn revoke(&mut self) {
+        self.revoked = true;
+    }
+
+    /// Leitura delimitada: o resultado é descartado se a contenção disparar durante o uso
+    pub fn read<R>(&mut self, f: impl FnOnce(&T) -> R) -> Result<R, AccessError> {
+        let started_at = self.begin(Operations::READ)?;
+        let result = {
+            let guard = self.data.lock().map_err(AccessError::Lock)?;
+            f(&guard)
+        };
+        self.finish(Operations::READ, started_at)?;
+        Ok(result)
+    }
+
+    /// Escrita transacional: aplicada sobre uma cópia e confirmada apenas se
+    /// a concessão continuar válida ao final do uso
+    pub fn write<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Result<R, AccessError>
+    where
+        T: Clone,
+    {
+        let started_at = self.begin(Operations::WRITE)?;
+        let data = self.data.clone();
+        let mut guard = data.lock().map_err(AccessError::Lock)?;
+        let mut scratch = (*guard).clone();
+        let result = f(&mut scratch);
+        self.finish(Operations::WRITE, started_at)?;
+        *guard = scratch;
+        Ok(result)
+    }
+
+    /// Projeta os dados em um valor próprio e encerra a concessão
+    pub fn map<U>(mut self, f: impl FnOnce(&T) -> U) -> Result<U, AccessError> {
+        let projected = self.read(f);
+        self.revoked = true;
+        projected
+    }
+
+    fn begin(&mut self, operation: Operations) -> Result<u64, AccessError> {
+        if !self.granted.contains(operation) {
+            return Err(AccessError::NotPermitted);
+        }
+        if self.is_revoked() {
+            self.revoked = true;
+            return Err(AccessError::Revoked);
+        }
+        if operation.contains(Operations::WRITE) && !self.containment.permits_writes() {
+            return Err(AccessError::WritesSuspended);
+        }
+        if self.records.len() >= MAX_ACCESS_RECORDS {
+            return Err(AccessError::RecordLimit);
+        }
+        Ok((self.clock)())
+    }
+
+    fn finish(&mut self, operation: Operations, started_at: u64) -> Result<(), AccessError> {
+        let ended_at = (self.clock)();
+        let expired = ended_at >= self.expires_at;
+        let interrupted = self.containment.revokes_grants()
+            || (operation.contains(Operations::WRITE) && !self.containment.permits_writes());
+        let outcome = if interrupted {
+            AccessOutcome::Interrupted
+        } else if expired {
+            AccessOutcome::Expired
+        } else {
+            AccessOutcome::Completed
+        };
+        self.records.push(AccessRecord {
+            operation,
+            started_at,
+            duration: ended_at.saturating_sub(started_at),
+            outcome,
+        });
+        if expired || self.containment.revokes_grants() {
+            self.revoked = true;
+            return Err(AccessError::Revoked);
+        }
+        if interrupted {
+            return Err(AccessError::WritesSuspended);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Containment;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    static TICKS: AtomicU64 = AtomicU64::new(0);
+
+    /// Relógio que avança 10 unidades a cada leitura
+    fn ticking() -> u64 {
+        TICKS.fetch_add(10, Ordering::SeqCst)
+    }
+
+    fn frozen() -> u64 {
+        0
+    }
+
+    fn grant(clock: fn() -> u64, expires_at: u64) -> (EthicalAccess<u32>, Arc<Mutex<u32>>, Arc<ContainmentSignal>) {
+        let data = Arc::new(Mutex::new(7));
+        let signal = Arc::new(ContainmentSignal::new());
+        let access = EthicalAccess::new(data.clone(), Operations::READ_WRITE, expires_at, clock, signal.clone());
+        (access, data, signal)
+    }
+
+    #[test]
+    fn write_that_outlives_the_grant_is_discarded() {
+        // `begin` lê 0 e 10 (concessão válida); `finish` lê 20 ≥ 15
+        let (mut access, data, _) = grant(ticking, 15);
+        assert_eq!(access.write(|v| *v = 99), Err(AccessError::Revoked));
+        assert_eq!(*data.lock().unwrap(), 7);
+        assert_eq!(access.records()[0].outcome, AccessOutcome::Expired);
+        assert!(access.is_revoked());
+    }
+
+    #[test]
+    fn write_commits_within_the_grant() {
+        let (mut access, data, _) = grant(frozen, 100);
+        assert_eq!(access.write(|v| core::mem::replace(v, 8)), Ok(7));
+        assert_eq!(*data.lock().unwrap(), 8);
+        assert_eq!(access.records()[0].outcome, AccessOutcome::Completed);
+    }
+
+    #[test]
+    fn containment_revokes_and_suspends() {
+        let (mut access, data, signal) = grant(frozen, 100);
+        signal.trigger(Containment::ReadOnly);
+        assert_eq!(access.write(|v| *v = 1), Err(AccessError::WritesSuspended));
+        assert_eq!(access.read(|v| *v), Ok(7));
+        signal.trigger(Containment::Quarantine);
+        assert_eq!(access.read(|v| *v), Err(AccessError::Revoked));
+        assert_eq!(*data.lock().unwrap(), 7);
+    }
+
+    #[test]
+    fn operations_outside_the_grant_are_refused() {
+        let data = Arc::new(Mutex::new(0u32));
+        let signal = Arc::new(ContainmentSignal::new());
+        let mut access = EthicalAccess::new(data, Operations::READ, 100, frozen, signal);
+        assert_eq!(access.write(|v| *v = 1), Err(AccessError::NotPermitted));
+        assert_eq!(access.map(|v| *v), Ok(0));
+    }
+}
@@ -0,0 +1,430 @@
+//! Atestações assinadas de transição de fase
+//! Uma `Attestation` compromete a identidade SASC (key id), o `temporal_nonce`, o
+//! instante, os hashes de métricas e limiares e o digest do `GateReport`, assinados
+//! em Ed25519. A codificação binária é fixa, de modo que verificadores externos
+//! validam a atestação só com a chave pública do atestador, sem acesso ao selo.
+
+use crate::crypto;
+use crate::gates::{GateReport, Phase, SASCIdentity};
+
+/// Domínio de separação da mensagem assinada
+pub const ATTESTATION_DOMAIN: &[u8] = b"SASC-ATTEST-v1";
+
+/// Domínio de separação do key id derivado da identidade
+pub const KEY_ID_DOMAIN: &[u8] = b"SASC-KEYID-v1";
+
+/// Versão da codificação binária
+pub const ATTESTATION_VERSION: u8 = 1;
+
+/// Corpo assinado: versão ‖ fase ‖ key id ‖ nonce ‖ instante ‖ 3 digests ‖ gates aprovados
+pub const ATTESTATION_BODY_LEN: usize = 123;
+
+/// Codificação completa: corpo ‖ assinatura
+pub const ATTESTATION_LEN: usize = ATTESTATION_BODY_LEN + 64;
+
+pub type KeyId = [u8; 8];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttestationError {
+    /// Tamanho diferente de `ATTESTATION_LEN`
+    Length,
+    UnsupportedVersion,
+    UnknownPhase,
+    InvalidSignature,
+    /// Key id diferente do esperado pelo verificador
+    KeyMismatch,
+    /// Nonce já consumido por uma atestação aceita
+    Replayed,
+    /// Instante posterior ao relógio do verificador (além da tolerância)
+    FromFuture,
+    /// Instante mais antigo que `max_age`
+    Stale,
+    /// Gates aprovados não cobrem os exigidos pela fase
+    IllegalPhase,
+    /// Bit de gates aprovados fora dos 7 gates (bit 7 ligado)
+    NonCanonical,
+    /// Chave de assinatura diferente de `prince_key` da identidade
+    UnboundKey,
+    /// O último nonce representável já foi consumido; nenhuma atestação nova é aceita
+    NonceExhausted,
+}
+
+/// Identificador curto da identidade: SHA-256(domínio ‖ prince_key ‖ cardinal_merkle)[..8]
+pub fn key_id(identity: &SASCIdentity) -> KeyId {
+    let mut hasher = crypto::Sha256::new();
+    hasher.update(KEY_ID_DOMAIN);
+    hasher.update(&identity.prince_key);
+    hasher.update(&identity.cardinal_merkle);
+    let digest = hasher.finalize();
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&digest[..8]);
+    id
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attestation {
+    pub phase: Phase,
+    pub key_id: KeyId,
+    pub temporal_nonce: u64,
+    pub timestamp: u64,
+    /// `TemporalMetrics::hash` no instante da transição
+    pub metrics_hash: [u8; 32],
+    /// `GateConfig::hash` dos limiares aplicados na transição
+    pub config_hash: [u8; 32],
+    /// `GateReport::digest` da avaliação que autorizou a transição
+    pub report_digest: [u8; 32],
+    /// Bit `n - 1` ligado = gate `n` aprovado
+    pub gates_passed: u8,
+    pub signature: [u8; 64],
+}
+
+impl Attestation {
+    /// Corpo canônico (LE), coberto pela assinatura
+    pub fn body(&self) -> [u8; ATTESTATION_BODY_LEN] {
+        let mut body = [0u8; ATTESTATION_BODY_LEN];
+        body[0] = ATTESTATION_VERSION;
+        body[1] = self.phase.code();
+        body[2..10].copy_from_slice(&self.key_id);
+        body[10..18].copy_from_slice(&self.temporal_nonce.to_le_bytes());
+        body[18..26].copy_from_slice(&self.timestamp.to_le_bytes());
+        body[26..58].copy_from_slice(&self.metrics_hash);
+        body[58..90].copy_from_slice(&self.config_hash);
+        body[90..122].copy_from_slice(&self.report_digest);
+        body[122] = self.gates_passed;
+        body
+    }
+
+    /// Mensagem assinada: domínio ‖ corpo
+    pub fn signing_message(&self) -> [u8; 14 + ATTESTATION_BODY_LEN] {
+        let mut msg = [0u8; 14 + ATTESTATION_BODY_LEN];
+        msg[..14].copy_from_slice(ATTESTATION_DOMAIN);
+        msg[14..].copy_from_slice(&self.body());
+        msg
+    }
+
+    pub fn encode(&self) -> [u8; ATTESTATION_LEN] {
+        let mut out = [0u8; ATTESTATION_LEN];
+        out[..ATTESTATION_BODY_LEN].copy_from_slice(&self.body());
+        out[ATTESTATION_BODY_LEN..].copy_from_slice(&self.signature);
+        out
+    }
+
+    /// Decodifica sem verificar a assinatura
+    pub fn decode(bytes: &[u8]) -> Result<Self, AttestationError> {
+        if bytes.len() != ATTESTATION_LEN {
+            return Err(AttestationError::Length);
+        }
+        if bytes[0] != ATTESTATION_VERSION {
+            return Err(AttestationError::UnsupportedVersion);
+        }
+        let phase = Phase::from_code(bytes[1]).ok_or(AttestationError::UnknownPhase)?;
+        let mut key_id = [0u8; 8];
+        key_id.copy_from_slice(&bytes[2..10]);
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[10..18]);
+        let temporal_nonce = u64::from_le_bytes(word);
+        word.copy_from_slice(&bytes[18..26]);
+        let timestamp = u64::from_le_bytes(word);
+        let mut metrics_hash = [0u8; 32];
+        metrics_hash.copy_from_slice(&bytes[26..58]);
+        let mut config_hash = [0u8; 32];
+        config_hash.copy_from_slice(&bytes[58..90]);
+        let mut report_digest = [0u8; 32];
+        report_digest.copy_from_slice(&bytes[90..122]);
+        if bytes[122] & 0x80 != 0 {
+            return Err(AttestationError::NonCanonical);
+        }
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(&bytes[ATTESTATION_BODY_LEN..]);
+        Ok(Self {
+            phase,
+            key_id,
+            temporal_nonce,
+            timestamp,
+            metrics_hash,
+            config_hash,
+            report_digest,
+            gates_passed: bytes[122],
+            signature,
+        })
+    }
+
+    pub fn verify_signature(&self, public_key: &[u8; 32]) -> bool {
+        crypto::verify(public_key, &self.signing_message(), &self.signature)
+    }
+
+    /// A fase atestada exige que todos os seus gates tenham sido aprovados
+    pub fn phase_legal(&self) -> bool {
+        let required = self.phase.required_gates();
+        self.gates_passed & required == required
+    }
+}
+
+/// Emissor de atestações: chave de assinatura vinculada a uma `SASCIdentity`.
+/// Cada atestação consome um `temporal_nonce`; após `u64::MAX` o emissor se esgota.
+pub struct Attester {
+    key: crypto::SigningKey,
+    key_id: KeyId,
+    /// None = nonces esgotados
+    temporal_nonce: Option<u64>,
+}
+
+impl Attester {
+    /// A chave precisa ser a `prince_key` da identidade, senão o key id anunciaria
+    /// uma identidade que a assinatura não comprova
+    pub fn new(identity: &SASCIdentity, key: crypto::SigningKey) -> Result<Self, AttestationError> {
+        if key.public_key() != identity.prince_key {
+            return Err(AttestationError::UnboundKey);
+        }
+        Ok(Self { key, key_id: key_id(identity), temporal_nonce: Some(identity.temporal_nonce) })
+    }
+
+    pub fn public_key(&self) -> [u8; 32] {
+        self.key.public_key()
+    }
+
+    pub fn key_id(&self) -> KeyId {
+        self.key_id
+    }
+
+    /// Nonce da próxima atestação (None = esgotado)
+    pub fn temporal_nonce(&self) -> Option<u64> {
+        self.temporal_nonce
+    }
+
+    pub fn attest(
+        &mut self,
+        phase: Phase,
+        metrics_hash: [u8; 32],
+        report: &GateReport,
+        now: u64,
+    ) -> Result<Attestation, AttestationError> {
+        let temporal_nonce = self.temporal_nonce.ok_or(AttestationError::NonceExhausted)?;
+        let gates_passed = report
+            .gates
+            .iter()
+            .filter(|g| g.passed)
+            .fold(0u8, |acc, g| acc | (1 << (g.gate.number() - 1)));
+        let mut attestation = Attestation {
+            phase,
+            key_id: self.key_id,
+            temporal_nonce,
+            timestamp: now,
+            metrics_hash,
+            config_hash: report.config_hash,
+            report_digest: report.digest(),
+            gates_passed,
+            signature: [0; 64],
+        };
+        attestation.signature = self.key.sign(&attestation.signing_message());
+        self.temporal_nonce = temporal_nonce.checked_add(1);
+        Ok(attestation)
+    }
+}
+
+/// Verificador externo: conhece apenas a chave pública do atestador e o key id
+/// esperado; guarda o próximo nonce aceitável para rejeitar repetições.
+pub struct AttestationVerifier {
+    pub public_key: [u8; 32],
+    pub key_id: KeyId,
+    /// Idade máxima aceita, em ticks do relógio do verificador
+    pub max_age: u64,
+    /// Tolerância para instantes à frente do relógio do verificador
+    pub max_skew: u64,
+    /// None = o nonce `u64::MAX` já foi aceito
+    next_nonce: Option<u64>,
+}
+
+impl AttestationVerifier {
+    pub fn new(public_key: [u8; 32], key_id: KeyId, first_nonce: u64, max_age: u64) -> Self {
+        Self { public_key, key_id, max_age, max_skew: 0, next_nonce: Some(first_nonce) }
+    }
+
+    /// Verificador para a identidade publicada (key id e nonce inicial)
+    pub fn for_identity(identity: &SASCIdentity, public_key: [u8; 32], max_age: u64) -> Self {
+        Self::new(public_key, key_id(identity), identity.temporal_nonce, max_age)
+    }
+
+    /// Menor nonce ainda aceitável (None = nenhum)
+    pub fn next_nonce(&self) -> Option<u64> {
+        self.next_nonce
+    }
+
+    /// Checa assinatura, key id, frescor (nonce e instante) e legalidade da fase,
+    /// sem consumir o nonce
+    pub fn check(&self, attestation: &Attestation, now: u64) -> Result<(), AttestationError> {
+        if attestation.key_id != self.key_id {
+            return Err(AttestationError::KeyMismatch);
+        }
+        if !attestation.verify_signature(&self.public_key) {
+            return Err(AttestationError::InvalidSignature);
+        }
+        let next_nonce = self.next_nonce.ok_or(AttestationError::NonceExhausted)?;
+        if attestation.temporal_nonce < next_nonce {
+            return Err(AttestationError::Replayed);
+        }
+        if attestation.timestamp > now.saturating_add(self.max_skew) {
+            return Err(AttestationError::FromFuture);
+        }
+        if now.saturating_sub(attestation.timestamp) > self.max_age {
+            return Err(AttestationError::Stale);
+        }
+        if !attestation.phase_legal() {
+            return Err(AttestationError::IllegalPhase);
+        }
+        Ok(())
+    }
+
+    /// Aceita a atestação e consome seu nonce
+    pub fn verify(&mut self, attestation: &Attestation, now: u64) -> Result<(), AttestationError> {
+        self.check(attestation, now)?;
+        self.next_nonce = attestation.temporal_nonce.checked_add(1);
+        Ok(())
+    }
+
+    /// Decodifica e verifica uma atestação recebida em bytes
+    pub fn verify_bytes(&mut self, bytes: &[u8], now: u64) -> Result<Attestation, AttestationError> {
+        let attestation = Attestation::decode(bytes)?;
+        self.verify(&attestation, now)?;
+        Ok(attestation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gates::fixture::{cardinal, identity, prince, seal, NOW};
+
+    fn attester_at(nonce: u64) -> (Attester, SASCIdentity) {
+        let identity = SASCIdentity { temporal_nonce: nonce, ..identity() };
+        (Attester::new(&identity, prince()).unwrap(), identity)
+    }
+
+    fn attest(attester: &mut Attester) -> Result<Attestation, AttestationError> {
+        let seal = seal();
+        let report = seal.check_transition(NOW).unwrap();
+        attester.attest(Phase::Superfluid, seal.metrics.hash(), &report, NOW)
+    }
+
+    #[test]
+    fn attester_key_must_be_the_prince_key() {
+        assert!(matches!(Attester::new(&identity(), cardinal()), Err(AttestationError::UnboundKey)));
+        let (mut attester, identity) = attester_at(0);
+        let attestation = attest(&mut attester).unwrap();
+        let mut verifier = AttestationVerifier::for_identity(&identity, identity.prince_key, 10);
+        assert_eq!(verifier.verify(&attestation, NOW), Ok(()));
+    }
+
+    #[test]
+    fn nonce_space_is_not_reused_after_exhaustion() {
+        let (mut attester, identity) = attester_at(u64::MAX);
+        let last = attest(&mut attester).unwrap();
+        assert_eq!(last.temporal_nonce, u64::MAX);
+        assert_eq!(attester.temporal_nonce(), None);
+        assert_eq!(attest(&mut attester), Err(AttestationError::NonceExhausted));
+
+        let mut verifier = AttestationVerifier::for_identity(&identity, identity.prince_key, 10);
+        assert_eq!(verifier.verify(&last, NOW), Ok(()));
+        assert_eq!(verifier.next_nonce(), None);
+        assert_eq!(verifier.verify(&last, NOW), Err(AttestationError::NonceExhausted));
+
+        // Um nonce 0 não volta a valer depois do último
+        let (mut restarted, _) = attester_at(0);
+        let wrapped = attest(&mut restarted).unwrap();
+        assert_eq!(verifier.verify(&wrapped, NOW), Err(AttestationError::NonceExhausted));
+    }
+
+    #[test]
+    fn decode_rejects_unknown_gate_bits() {
+        let (mut attester, _) = attester_at(0);
+        let bytes = attest(&mut attester).unwrap().encode();
+        assert_eq!(Attestation::decode(&bytes).map(|a| a.encode()), Ok(bytes));
+        let mut eighth = bytes;
+        eighth[122] |= 0x80;
+        assert_eq!(Attestation::decode(&eighth), Err(AttestationError::NonCanonical));
+    }
+
+    fn verifier(identity: &SASCIdentity) -> AttestationVerifier {
+        AttestationVerifier::for_identity(identity, identity.prince_key, 10)
+    }
+
+    #[test]
+    fn valid_attestation_round_trips_through_bytes() {
+        let (mut attester, identity) = attester_at(7);
+        let attestation = attest(&mut attester).unwrap();
+        assert_eq!((attestation.temporal_nonce, attestation.gates_passed), (7, 0x7f));
+        assert_eq!(attestation.key_id, key_id(&identity));
+
+        let mut verifier = verifier(&identity);
+        assert_eq!(verifier.verify_bytes(&attestation.encode(), NOW), Ok(attestation));
+        assert_eq!(verifier.next_nonce(), Some(8));
+        // Nonces podem pular adiante, nunca voltar
+        attest(&mut attester).unwrap();
+        let later = attest(&mut attester).unwrap();
+        assert_eq!(verifier.verify(&later, NOW), Ok(()));
+        assert_eq!(verifier.next_nonce(), Some(10));
+    }
+
+    #[test]
+    fn tampered_bytes_fail_verification() {
+        let (mut attester, identity) = attester_at(0);
+        let bytes = attest(&mut attester).unwrap().encode();
+        let mut verifier = verifier(&identity);
+        for offset in [10, 18, 30, 60, 100, 122, ATTESTATION_BODY_LEN + 5] {
+            let mut tampered = bytes;
+            tampered[offset] ^= 1;
+            assert_eq!(verifier.verify_bytes(&tampered, NOW), Err(AttestationError::InvalidSignature), "{offset}");
+        }
+        let mut foreign = bytes;
+        foreign[2] ^= 1;
+        assert_eq!(verifier.verify_bytes(&foreign, NOW), Err(AttestationError::KeyMismatch));
+        let stranger = AttestationVerifier::for_identity(&identity, cardinal().public_key(), 10);
+        assert_eq!(stranger.check(&Attestation::decode(&bytes).unwrap(), NOW), Err(AttestationError::InvalidSignature));
+
+        assert_eq!(Attestation::decode(&bytes[1..]), Err(AttestationError::Length));
+        let mut version = bytes;
+        version[0] = 2;
+        assert_eq!(Attestation::decode(&version), Err(AttestationError::UnsupportedVersion));
+        let mut phase = bytes;
+        phase[1] = 0;
+        assert_eq!(Attestation::decode(&phase), Err(AttestationError::UnknownPhase));
+        assert_eq!(verifier.next_nonce(), Some(0));
+    }
+
+    #[test]
+    fn replayed_nonces_are_rejected() {
+        let (mut attester, identity) = attester_at(0);
+        let first = attest(&mut attester).unwrap();
+        let second = attest(&mut attester).unwrap();
+        let mut verifier = verifier(&identity);
+        verifier.verify(&second, NOW).unwrap();
+        assert_eq!(verifier.verify(&second, NOW), Err(AttestationError::Replayed));
+        assert_eq!(verifier.verify(&first, NOW), Err(AttestationError::Replayed));
+    }
+
+    #[test]
+    fn timestamps_outside_the_window_are_rejected() {
+        let (mut attester, identity) = attester_at(0);
+        let attestation = attest(&mut attester).unwrap();
+        let mut verifier = verifier(&identity);
+        assert_eq!(verifier.check(&attestation, NOW - 1), Err(AttestationError::FromFuture));
+        verifier.max_skew = 1;
+        assert_eq!(verifier.check(&attestation, NOW - 1), Ok(()));
+        assert_eq!(verifier.check(&attestation, NOW + 10), Ok(()));
+        assert_eq!(verifier.check(&attestation, NOW + 11), Err(AttestationError::Stale));
+        // Rejeições não consomem o nonce
+        assert_eq!(verifier.verify(&attestation, NOW), Ok(()));
+    }
+
+    #[test]
+    fn phase_requires_every_gate() {
+        let (mut attester, identity) = attester_at(0);
+        let mut breached = seal();
+        breached.metrics.firewall_expansion = 0.95;
+        let report = breached.evaluate_all(NOW).unwrap();
+        let attestation = attester.attest(Phase::Superfluid, breached.metrics.hash(), &report, NOW).unwrap();
+        assert_eq!(attestation.gates_passed, 0x7f & !(1 << 3));
+        assert!(!attestation.phase_legal());
+        assert_eq!(verifier(&identity).verify(&attestation, NOW), Err(AttestationError::IllegalPhase));
+    }
+}
@@ -0,0 +1,779 @@
+//! Registro de auditoria encadeado por hash
+//! Cada decisão da `EthicalBoundary` vira uma entrada que se compromete com o hash
+//! da anterior; a verificação detecta truncamento, reordenação e adulteração.
+//! Além dos pedidos de acesso, registra cada evento do protocolo de contenção e degelo.
+
+use alloc::vec::Vec;
+
+use crate::access::AccessRequest;
+use crate::containment::{Containment, RecoveryEvent};
+use crate::crypto::Sha256;
+use crate::voting::{QuorumRule, VoteOutcome, VoteResult};
+
+/// Domínio de separação dos hashes de entrada
+pub const AUDIT_DOMAIN: &[u8] = b"SASC-AUDIT-v2";
+
+/// Hash anterior da primeira entrada (sequência 0)
+pub const AUDIT_GENESIS: [u8; 32] = [0; 32];
+
+/// Capacidade padrão do anel em memória
+pub const AUDIT_RING_CAPACITY: usize = 256;
+
+/// Tamanho do corpo canônico (sem o hash) e da entrada completa
+pub const AUDIT_BODY_LEN: usize = 171;
+pub const AUDIT_ENTRY_LEN: usize = AUDIT_BODY_LEN + 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditError {
+    /// Falha de E/S do sink persistente
+    Sink,
+    /// Codificação inválida ao ler entradas persistidas
+    Malformed,
+    /// Cadeia persistida não passa na verificação; não é retomada
+    Tampered(AuditViolation),
+    /// Sequência esgotada: nenhuma entrada nova pode ser numerada
+    Exhausted,
+}
+
+/// Violações detectadas pela verificação da cadeia
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditViolation {
+    Empty,
+    /// Hash recalculado não confere: conteúdo adulterado
+    Modified { sequence: u64 },
+    /// Sequência regride ou se repete: entradas reordenadas
+    Reordered { index: usize },
+    /// Sequência salta: entradas removidas do meio
+    Gap { expected: u64, found: u64 },
+    /// `prev_hash` não aponta para a entrada anterior
+    BrokenLink { sequence: u64 },
+    /// Primeira entrada não parte do gênesis nem da âncora informada
+    BadOrigin,
+    /// A cadeia termina antes do ponto de controle conhecido
+    Truncated { expected: u64, found: u64 },
+    /// Entrada posterior à sequência máxima representável
+    Overflow { index: usize },
+}
+
+/// Resumo da apuração do Sínodo; `outcome: None` quando não houve avaliação
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoteSummary {
+    pub outcome: Option<VoteOutcome>,
+    pub rule: Option<QuorumRule>,
+    pub approve: u32,
+    pub reject: u32,
+    pub abstain: u32,
+    pub missing: u32,
+    pub approve_weight: u64,
+    pub total_weight: u64,
+}
+
+impl From<&VoteResult> for VoteSummary {
+    fn from(result: &VoteResult) -> Self {
+        Self {
+            outcome: Some(result.outcome),
+            rule: Some(result.rule),
+            approve: result.approve,
+            reject: result.reject,
+            abstain: result.abstain,
+            missing: result.missing.len() as u32,
+            approve_weight: result.approve_weight,
+            total_weight: result.total_weight,
+        }
+    }
+}
+
+/// Natureza da entrada
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditKind {
+    /// Decisão sobre um `AccessRequest`; recusas sem contenção têm
+    /// `granted: false` e `containment: None`
+    Access { granted: bool },
+    /// Evento do protocolo de contenção e degelo (`RecoveryEvent::code`);
+    /// `accepted` indica se o estado avançou
+    Recovery { event: u8, accepted: bool },
+}
+
+impl AuditKind {
+    fn encode(self) -> [u8; 3] {
+        match self {
+            AuditKind::Access { granted } => [0, 0, granted as u8],
+            AuditKind::Recovery { event, accepted } => [1, event, accepted as u8],
+        }
+    }
+
+    fn decode(bytes: [u8; 3]) -> Result<Self, AuditError> {
+        match bytes {
+            [0, 0, granted @ (0 | 1)] => Ok(AuditKind::Access { granted: granted == 1 }),
+            [1, event, accepted @ (0 | 1)] if RecoveryEvent::is_code(event) => {
+                Ok(AuditKind::Recovery { event, accepted: accepted == 1 })
+            }
+            _ => Err(AuditError::Malformed),
+        }
+    }
+}
+
+/// Conteúdo de uma decisão, antes do encadeamento
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuditRecord {
+    pub kind: AuditKind,
+    pub timestamp: u64,
+    pub request_digest: [u8; 32],
+    pub requester: [u8; 32],
+    pub purpose: u8,
+    pub operations: u8,
+    pub sensitivity: u8,
+    /// `None` quando a contenção vigente recusou antes das verificações
+    pub veto_approved: Option<bool>,
+    pub vote: VoteSummary,
+    pub phi: f64,
+    pub coherence_stable: Option<bool>,
+    /// Nível de contenção que recusou o pedido (em eventos de recuperação, o nível resultante)
+    pub containment: Option<Containment>,
+}
+
+impl AuditRecord {
+    pub fn for_request(request: &AccessRequest, timestamp: u64) -> Self {
+        Self {
+            kind: AuditKind::Access { granted: false },
+            timestamp,
+            request_digest: request.digest(),
+            requester: request.requester,
+            purpose: request.purpose.code(),
+            operations: request.operations.bits(),
+            sensitivity: request.sensitivity.code(),
+            veto_approved: None,
+            vote: VoteSummary::default(),
+            phi: f64::NAN,
+            coherence_stable: None,
+            containment: None,
+        }
+    }
+
+    /// Evento de recuperação: `request_digest` guarda a moção de degelo, quando
+    /// houver, e `phi` a coerência medida na retomada
+    pub fn for_recovery(event: RecoveryEvent, accepted: bool, timestamp: u64) -> Self {
+        let (motion, phi) = match event {
+            RecoveryEvent::RequestThaw { motion } | RecoveryEvent::VotePassed { motion } => (motion, f64::NAN),
+            RecoveryEvent::Resume { phi } => ([0; 32], phi),
+            _ => ([0; 32], f64::NAN),
+        };
+        Self {
+            kind: AuditKind::Recovery { event: event.code(), accepted },
+            timestamp,
+            request_digest: motion,
+            requester: [0; 32],
+            purpose: 0,
+            operations: 0,
+            sensitivity: 0,
+            veto_approved: None,
+            vote: VoteSummary::default(),
+            phi,
+            coherence_stable: None,
+            containment: None,
+        }
+    }
+}
+
+/// Entrada encadeada
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuditEntry {
+    pub sequence: u64,
+    pub prev_hash: [u8; 32],
+    pub record: AuditRecord,
+    pub hash: [u8; 32],
+}
+
+fn tri_state(value: Option<bool>) -> u8 {
+    match value {
+        None => 0,
+        Some(false) => 1,
+        Some(true) => 2,
+    }
+}
+
+fn from_tri_state(code: u8) -> Result<Option<bool>, AuditError> {
+    match code {
+        0 => Ok(None),
+        1 => Ok(Some(false)),
+        2 => Ok(Some(true)),
+        _ => Err(AuditError::Malformed),
+    }
+}
+
+fn outcome_code(outcome: Option<VoteOutcome>) -> u8 {
+    match outcome {
+        None => 0,
+        Some(VoteOutcome::Approved) => 1,
+        Some(VoteOutcome::Rejected) => 2,
+        Some(VoteOutcome::Pending) => 3,
+    }
+}
+
+fn outcome_from_code(code: u8) -> Result<Option<VoteOutcome>, AuditError> {
+    match code {
+        0 => Ok(None),
+        1 => Ok(Some(VoteOutcome::Approved)),
+        2 => Ok(Some(VoteOutcome::Rejected)),
+        3 => Ok(Some(VoteOutcome::Pending)),
+        _ => Err(AuditError::Malformed),
+    }
+}
+
+fn rule_code(rule: Option<QuorumRule>) -> (u8, u32, u32) {
+    match rule {
+        None => (0, 0, 0),
+        Some(QuorumRule::Unanimity) => (1, 0, 0),
+        Some(QuorumRule::Supermajority { numerator, denominator }) => (2, numerator, denominator),
+        Some(QuorumRule::Weighted { threshold_bps }) => (3, threshold_bps, 0),
+        Some(QuorumRule::Standing) => (4, 0, 0),
+    }
+}
+
+fn rule_from_code(code: u8, a: u32, b: u32) -> Result<Option<QuorumRule>, AuditError> {
+    match code {
+        0 => Ok(None),
+        1 => Ok(Some(QuorumRule::Unanimity)),
+        2 => Ok(Some(QuorumRule::Supermajority { numerator: a, denominator: b })),
+        3 => Ok(Some(QuorumRule::Weighted { threshold_bps: a })),
+        4 => Ok(Some(QuorumRule::Standing)),
+        _ => Err(AuditError::Malformed),
+    }
+}
+
+/// Cursor de escrita sobre um buffer de tamanho fixo
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl Writer<'_> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.buf[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+    }
+}
+
+/// Cursor de leitura correspondente
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        out.copy_from_slice(&self.buf[self.pos..self.pos + N]);
+        self.pos += N;
+        out
+    }
+
+    fn u8(&mut self) -> u8 {
+        self.take::<1>()[0]
+    }
+
+    fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.take())
+    }
+
+    fn u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.take())
+    }
+}
+
+impl AuditEntry {
+    /// Corpo canônico (little-endian, tamanho fixo) coberto pelo hash
+    pub fn encode_body(&self) -> [u8; AUDIT_BODY_LEN] {
+        let r = &self.record;
+        let (rule, rule_a, rule_b) = rule_code(r.vote.rule);
+        let containment = r.containment.map_or(0, Containment::code);
+
+        let mut body = [0u8; AUDIT_BODY_LEN];
+        let mut w = Writer { buf: &mut body, pos: 0 };
+        w.put(&self.sequence.to_le_bytes());
+        w.put(&r.timestamp.to_le_bytes());
+        w.put(&self.prev_hash);
+        w.put(&r.request_digest);
+        w.put(&r.requester);
+        w.put(&[r.purpose, r.operations, r.sensitivity, tri_state(r.veto_approved)]);
+        w.put(&[outcome_code(r.vote.outcome), rule]);
+        w.put(&rule_a.to_le_bytes());
+        w.put(&rule_b.to_le_bytes());
+        w.put(&r.vote.approve.to_le_bytes());
+        w.put(&r.vote.reject.to_le_bytes());
+        w.put(&r.vote.abstain.to_le_bytes());
+        w.put(&r.vote.missing.to_le_bytes());
+        w.put(&r.vote.approve_weight.to_le_bytes());
+        w.put(&r.vote.total_weight.to_le_bytes());
+        w.put(&r.phi.to_bits().to_le_bytes());
+        w.put(&[tri_state(r.coherence_stable), containment]);
+        w.put(&r.kind.encode());
+        debug_assert_eq!(w.pos, AUDIT_BODY_LEN);
+        body
+    }
+
+    pub fn compute_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(AUDIT_DOMAIN);
+        hasher.update(&self.encode_body());
+        hasher.finalize()
+    }
+
+    pub fn encode(&self) -> [u8; AUDIT_ENTRY_LEN] {
+        let mut out = [0u8; AUDIT_ENTRY_LEN];
+        out[..AUDIT_BODY_LEN].copy_from_slice(&self.encode_body());
+        out[AUDIT_BODY_LEN..].copy_from_slice(&self.hash);
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, AuditError> {
+        if bytes.len() != AUDIT_ENTRY_LEN {
+            return Err(AuditError::Malformed);
+        }
+        let mut r = Reader { buf: bytes, pos: 0 };
+        let sequence = r.u64();
+        let timestamp = r.u64();
+        let prev_hash = r.take::<32>();
+        let request_digest = r.take::<32>();
+        let requester = r.take::<32>();
+        let [purpose, operations, sensitivity, veto] = r.take::<4>();
+        let outcome = r.u8();
+        let rule = r.u8();
+        let (rule_a, rule_b) = (r.u32(), r.u32());
+        let vote = VoteSummary {
+            outcome: outcome_from_code(outcome)?,
+            rule: rule_from_code(rule, rule_a, rule_b)?,
+            approve: r.u32(),
+            reject: r.u32(),
+            abstain: r.u32(),
+            missing: r.u32(),
+            approve_weight: r.u64(),
+            total_weight: r.u64(),
+        };
+        let phi = f64::from_bits(r.u64());
+        let coherence = r.u8();
+        let containment = match r.u8() {
+            0 => None,
+            code => Some(Containment::from_code(code).ok_or(AuditError::Malformed)?),
+        };
+        let kind = AuditKind::decode(r.take::<3>())?;
+        let hash = r.take::<32>();
+
+        Ok(Self {
+            sequence,
+            prev_hash,
+            record: AuditRecord {
+                kind,
+                timestamp,
+                request_digest,
+                requester,
+                purpose,
+                operations,
+                sensitivity,
+                veto_approved: from_tri_state(veto)?,
+                vote,
+                phi,
+                coherence_stable: from_tri_state(coherence)?,
+                containment,
+            },
+            hash,
+        })
+    }
+}
+
+/// Ponto de controle da cadeia (publicado ou atestado externamente)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub sequence: u64,
+    pub hash: [u8; 32],
+}
+
+/// Destino de entradas encadeadas
+pub trait AuditSink {
+    fn record(&mut self, entry: &AuditEntry) -> Result<(), AuditError>;
+}
+
+/// Anel em memória de capacidade fixa, alocado na inicialização
+pub struct AuditRing {
+    entries: Vec<AuditEntry>,
+    capacity: usize,
+    oldest: usize,
+}
+
+impl AuditRing {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { entries: Vec::with_capacity(capacity.max(1)), capacity: capacity.max(1), oldest: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entradas retidas, da mais antiga à mais recente
+    pub fn snapshot(&self) -> Vec<AuditEntry> {
+        let mut out = Vec::with_capacity(self.entries.len());
+        out.extend_from_slice(&self.entries[self.oldest..]);
+        out.extend_from_slice(&self.entries[..self.oldest]);
+        out
+    }
+}
+
+impl AuditSink for AuditRing {
+    fn record(&mut self, entry: &AuditEntry) -> Result<(), AuditError> {
+        if self.entries.len() < self.capacity {
+            self.entries.push(*entry);
+        } else {
+            self.entries[self.oldest] = *entry;
+            self.oldest = (self.oldest + 1) % self.capacity;
+        }
+        Ok(())
+    }
+}
+
+/// Sink persistente: anexa entradas codificadas a um arquivo
+#[cfg(feature = "std")]
+pub struct FileSink {
+    file: std::fs::File,
+}
+
+#[cfg(feature = "std")]
+impl FileSink {
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self, AuditError> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|_| AuditError::Sink)?;
+        Ok(Self { file })
+    }
+
+    /// Reabre um arquivo existente: verifica a cadeia gravada e devolve o
+    /// sink junto com o ponto de controle da última entrada (`None` se vazio).
+    /// Uma entrada final parcial (queda durante a gravação) nunca foi encadeada:
+    /// é truncada antes da verificação.
+    pub fn resume<P: AsRef<std::path::Path>>(path: P) -> Result<(Self, Option<Checkpoint>), AuditError> {
+        let sink = Self::open(&path)?;
+        let mut bytes = std::fs::read(&path).map_err(|_| AuditError::Sink)?;
+        let whole = bytes.len() - bytes.len() % AUDIT_ENTRY_LEN;
+        if whole != bytes.len() {
+            sink.file.set_len(whole as u64).map_err(|_| AuditError::Sink)?;
+            sink.file.sync_data().map_err(|_| AuditError::Sink)?;
+            bytes.truncate(whole);
+        }
+        let entries = decode_entries(&bytes)?;
+        if entries.is_empty() {
+            return Ok((sink, None));
+        }
+        verify_chain(&entries, None, None).map_err(AuditError::Tampered)?;
+        let last = &entries[entries.len() - 1];
+        Ok((sink, Some(Checkpoint { sequence: last.sequence, hash: last.hash })))
+    }
+
+    /// Lê todas as entradas de um arquivo para verificação
+    pub fn read_entries<P: AsRef<std::path::Path>>(path: P) -> Result<Vec<AuditEntry>, AuditError> {
+        let bytes = std::fs::read(path).map_err(|_| AuditError::Sink)?;
+        decode_entries(&bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+fn decode_entries(bytes: &[u8]) -> Result<Vec<AuditEntry>, AuditError> {
+    if !bytes.len().is_multiple_of(AUDIT_ENTRY_LEN) {
+        return Err(AuditError::Malformed);
+    }
+    bytes.chunks_exact(AUDIT_ENTRY_LEN).map(AuditEntry::decode).collect()
+}
+
+#[cfg(feature = "std")]
+impl AuditSink for FileSink {
+    fn record(&mut self, entry: &AuditEntry) -> Result<(), AuditError> {
+        use std::io::Write;
+        self.file.write_all(&entry.encode()).map_err(|_| AuditError::Sink)?;
+        // A cadeia só avança depois que a entrada chegou ao disco
+        self.file.sync_data().map_err(|_| AuditError::Sink)
+    }
+}
+
+/// Registro de auditoria: estado da cadeia, anel em memória e sink persistente opcional
+pub struct AuditLog {
+    next_sequence: u64,
+    head: [u8; 32],
+    pub ring: AuditRing,
+    #[cfg(feature = "std")]
+    pub file: Option<FileSink>,
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self::new(AUDIT_RING_CAPACITY)
+    }
+}
+
+impl AuditLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            next_sequence: 0,
+            head: AUDIT_GENESIS,
+            ring: AuditRing::with_capacity(capacity),
+            #[cfg(feature = "std")]
+            file: None,
+        }
+    }
+
+    /// Continua uma cadeia a partir do ponto de controle de sua última entrada
+    pub fn from_checkpoint(checkpoint: Checkpoint, capacity: usize) -> Result<Self, AuditError> {
+        let mut log = Self::new(capacity);
+        log.next_sequence = checkpoint.sequence.checked_add(1).ok_or(AuditError::Exhausted)?;
+        log.head = checkpoint.hash;
+        Ok(log)
+    }
+
+    /// Retoma o registro persistido em `path`: a numeração e o hash anterior
+    /// continuam da última entrada verificada do arquivo
+    #[cfg(feature = "std")]
+    pub fn resume<P: AsRef<std::path::Path>>(path: P, capacity: usize) -> Result<Self, AuditError> {
+        let (sink, checkpoint) = FileSink::resume(path)?;
+        let mut log = match checkpoint {
+            Some(checkpoint) => Self::from_checkpoint(checkpoint, capacity)?,
+            None => Self::new(capacity),
+        };
+        log.file = Some(sink);
+        Ok(log)
+    }
+
+    pub fn checkpoint(&self) -> Option<Checkpoint> {
+        self.next_sequence.checked_sub(1).map(|sequence| Checkpoint { sequence, hash: self.head })
+    }
+
+    /// Encadeia e grava um registro; a cadeia só avança se todos os sinks aceitarem
+    pub fn append(&mut self, record: AuditRecord) -> Result<AuditEntry, AuditError> {
+        let next_sequence = self.next_sequence.checked_add(1).ok_or(AuditError::Exhausted)?;
+        let mut entry = AuditEntry { sequence: self.next_sequence, prev_hash: self.head, record, hash: [0; 32] };
+        entry.hash = entry.compute_hash();
+
+        #[cfg(feature = "std")]
+        {
+            if let Some(file) = self.file.as_mut() {
+                file.record(&entry)?;
+            }
+        }
+        self.ring.record(&entry)?;
+
+        self.head = entry.hash;
+        self.next_sequence = next_sequence;
+        Ok(entry)
+    }
+}
+
+/// Verifica a integridade de uma sequência de entradas.
+/// `origin`: ponto de controle imediatamente anterior à primeira entrada (anéis que já
+/// descartaram o início); `head`: último ponto de controle conhecido, para detectar truncamento.
+pub fn verify_chain(
+    entries: &[AuditEntry],
+    origin: Option<Checkpoint>,
+    head: Option<Checkpoint>,
+) -> Result<(), AuditViolation> {
+    let first = entries.first().ok_or(AuditViolation::Empty)?;
+    let origin_ok = match origin {
+        Some(c) => c.sequence.checked_add(1) == Some(first.sequence) && first.prev_hash == c.hash,
+        None => first.sequence == 0 && first.prev_hash == AUDIT_GENESIS,
+    };
+    if !origin_ok {
+        return Err(AuditViolation::BadOrigin);
+    }
+
+    for (index, entry) in entries.iter().enumerate() {
+        if entry.compute_hash() != entry.hash {
+            return Err(AuditViolation::Modified { sequence: entry.sequence });
+        }
+        if index == 0 {
+            continue;
+        }
+        let previous = &entries[index - 1];
+        let expected = previous.sequence.checked_add(1).ok_or(AuditViolation::Overflow { index })?;
+        if entry.sequence < expected {
+            return Err(AuditViolation::Reordered { index });
+        }
+        if entry.sequence > expected {
+            return Err(AuditViolation::Gap { expected, found: entry.sequence });
+        }
+        if entry.prev_hash != previous.hash {
+            return Err(AuditViolation::BrokenLink { sequence: entry.sequence });
+        }
+    }
+
+    if let (Some(head), Some(last)) = (head, entries.last()) {
+        if last.sequence < head.sequence {
+            return Err(AuditViolation::Truncated { expected: head.sequence, found: last.sequence });
+        }
+        let anchored = entries.iter().find(|e| e.sequence == head.sequence);
+        if anchored.is_none_or(|e| e.hash != head.hash) {
+            return Err(AuditViolation::Modified { sequence: head.sequence });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access::{Operations, Purpose, Sensitivity};
+    use crate::crypto::SigningKey;
+    use alloc::string::String;
+
+    fn record(timestamp: u64) -> AuditRecord {
+        let key = SigningKey::from_seed(&[11; 32]);
+        let request =
+            AccessRequest::sign(&key, Purpose::Research, Operations::READ, Sensitivity::Internal, String::new());
+        AuditRecord::for_request(&request, timestamp)
+    }
+
+    fn chain(len: u64) -> Vec<AuditEntry> {
+        let mut log = AuditLog::new(16);
+        for t in 0..len {
+            log.append(record(t)).unwrap();
+        }
+        log.ring.snapshot()
+    }
+
+    /// Regrava o hash após alterar a entrada (adulteração que recalcula o próprio hash)
+    fn rehash(mut entry: AuditEntry) -> AuditEntry {
+        entry.hash = entry.compute_hash();
+        entry
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let entries = chain(5);
+        let head = Checkpoint { sequence: 4, hash: entries[4].hash };
+        assert_eq!(verify_chain(&entries, None, Some(head)), Ok(()));
+        let origin = Checkpoint { sequence: 1, hash: entries[1].hash };
+        assert_eq!(verify_chain(&entries[2..], Some(origin), Some(head)), Ok(()));
+        assert_eq!(verify_chain(&[], None, None), Err(AuditViolation::Empty));
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let entries = chain(5);
+
+        let mut modified = entries.clone();
+        modified[2].record.phi = 0.9;
+        assert_eq!(verify_chain(&modified, None, None), Err(AuditViolation::Modified { sequence: 2 }));
+
+        let mut reordered = entries.clone();
+        reordered.swap(2, 3);
+        assert_eq!(verify_chain(&reordered, None, None), Err(AuditViolation::Gap { expected: 2, found: 3 }));
+        reordered = entries.clone();
+        reordered[3] = entries[1];
+        assert_eq!(verify_chain(&reordered, None, None), Err(AuditViolation::Reordered { index: 3 }));
+
+        let mut gap = entries.clone();
+        gap.remove(2);
+        assert_eq!(verify_chain(&gap, None, None), Err(AuditViolation::Gap { expected: 2, found: 3 }));
+
+        let mut relinked = entries.clone();
+        relinked[3].prev_hash = [0xaa; 32];
+        relinked[3] = rehash(relinked[3]);
+        assert_eq!(verify_chain(&relinked, None, None), Err(AuditViolation::BrokenLink { sequence: 3 }));
+
+        assert_eq!(verify_chain(&entries[1..], None, None), Err(AuditViolation::BadOrigin));
+        let wrong_origin = Checkpoint { sequence: 0, hash: [1; 32] };
+        assert_eq!(verify_chain(&entries[1..], Some(wrong_origin), None), Err(AuditViolation::BadOrigin));
+    }
+
+    #[test]
+    fn truncation_against_a_checkpoint_is_detected() {
+        let entries = chain(5);
+        let head = Checkpoint { sequence: 4, hash: entries[4].hash };
+        assert_eq!(
+            verify_chain(&entries[..3], None, Some(head)),
+            Err(AuditViolation::Truncated { expected: 4, found: 2 })
+        );
+        let forked = Checkpoint { sequence: 4, hash: [7; 32] };
+        assert_eq!(verify_chain(&entries, None, Some(forked)), Err(AuditViolation::Modified { sequence: 4 }));
+    }
+
+    #[test]
+    fn sequence_overflow_is_an_error() {
+        let exhausted = Checkpoint { sequence: u64::MAX, hash: [0; 32] };
+        assert_eq!(AuditLog::from_checkpoint(exhausted, 4).err(), Some(AuditError::Exhausted));
+        let last = Checkpoint { sequence: u64::MAX - 1, hash: [3; 32] };
+        assert_eq!(AuditLog::from_checkpoint(last, 4).unwrap().append(record(0)), Err(AuditError::Exhausted));
+
+        // Entrada após u64::MAX não pode voltar a 0
+        let before = Checkpoint { sequence: u64::MAX - 2, hash: [3; 32] };
+        let top = AuditLog::from_checkpoint(before, 4).unwrap().append(record(0)).unwrap();
+        let beyond = rehash(AuditEntry { sequence: u64::MAX, prev_hash: top.hash, ..top });
+        let wrapped = rehash(AuditEntry { sequence: 0, prev_hash: beyond.hash, ..top });
+        let entries = [top, beyond, wrapped];
+        assert_eq!(verify_chain(&entries[..2], Some(before), None), Ok(()));
+        assert_eq!(verify_chain(&entries, Some(before), None), Err(AuditViolation::Overflow { index: 2 }));
+    }
+
+    #[test]
+    fn entries_round_trip() {
+        let mut entries = chain(1);
+        let mut recovery = AuditRecord::for_recovery(RecoveryEvent::RequestThaw { motion: [4; 32] }, false, 9);
+        recovery.containment = Some(Containment::Quarantine);
+        let mut log = AuditLog::new(2);
+        entries.push(log.append(recovery).unwrap());
+        for entry in &entries {
+            let decoded = AuditEntry::decode(&entry.encode()).unwrap();
+            assert_eq!(decoded.encode(), entry.encode());
+            assert_eq!(decoded.record.kind, entry.record.kind);
+        }
+        let mut bad_kind = entries[1].encode();
+        bad_kind[AUDIT_BODY_LEN - 2] = 0;
+        assert_eq!(AuditEntry::decode(&bad_kind), Err(AuditError::Malformed));
+        assert_eq!(AuditEntry::decode(&bad_kind[1..]), Err(AuditError::Malformed));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_log_resumes_from_its_last_entry() {
+        extern crate std;
+        let path = std::env::temp_dir().join(std::format!("sasc-audit-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut log = AuditLog::resume(&path, 4).unwrap();
+        assert_eq!(log.checkpoint(), None);
+        for t in 0..3 {
+            log.append(record(t)).unwrap();
+        }
+        let head = log.checkpoint().unwrap();
+        drop(log);
+
+        let mut resumed = AuditLog::resume(&path, 4).unwrap();
+        assert_eq!(resumed.checkpoint(), Some(head));
+        let next = resumed.append(record(3)).unwrap();
+        assert_eq!((next.sequence, next.prev_hash), (3, head.hash));
+        drop(resumed);
+
+        let persisted = FileSink::read_entries(&path).unwrap();
+        let tip = Checkpoint { sequence: 3, hash: next.hash };
+        assert_eq!(verify_chain(&persisted, None, Some(tip)), Ok(()));
+
+        // Queda no meio de uma gravação: a entrada parcial é truncada e a cadeia continua
+        let mut torn = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        std::io::Write::write_all(&mut torn, &[0xab; AUDIT_ENTRY_LEN / 2]).unwrap();
+        drop(torn);
+        assert_eq!(FileSink::read_entries(&path), Err(AuditError::Malformed));
+        let mut recovered = AuditLog::resume(&path, 4).unwrap();
+        assert_eq!(recovered.checkpoint(), Some(tip));
+        assert_eq!(recovered.append(record(4)).unwrap().sequence, 4);
+        drop(recovered);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 5 * AUDIT_ENTRY_LEN as u64);
+        assert_eq!(FileSink::read_entries(&path).unwrap().len(), 5);
+
+        // Arquivo adulterado não é retomado
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[AUDIT_ENTRY_LEN + 20] ^= 1;
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(matches!(AuditLog::resume(&path, 4), Err(AuditError::Tampered(_))));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,499 @@
+//! Hiranyagarbha: armazenamento de snapshots em redundância tripla
+//! Cada snapshot é gravado em três réplicas independentes com contador de geração
+//! e checksum próprios. A verificação exige as três em acordo; o reparo usa voto
+//! majoritário (2 de 3) para regravar a réplica divergente.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::crypto;
+
+/// Domínio de separação do checksum de réplica
+pub const BACKUP_DOMAIN: &[u8] = b"SASC-BACKUP-v1";
+
+/// Marca de início de imagem
+pub const REPLICA_MAGIC: [u8; 4] = *b"HGB1";
+
+/// Cabeçalho: marca ‖ geração (LE) ‖ tamanho (LE) ‖ checksum
+pub const REPLICA_HEADER_LEN: usize = 48;
+
+/// Maior snapshot aceito, em bytes
+pub const MAX_SNAPSHOT_LEN: usize = 64 * 1024;
+
+/// Número de réplicas e mínimo para o voto majoritário
+pub const REPLICAS: usize = 3;
+pub const MAJORITY: usize = 2;
+
+/// Tamanho de página do flash simulado
+pub const FLASH_PAGE_SIZE: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupError {
+    /// Falha de leitura ou escrita no meio físico
+    Io,
+    /// Snapshot maior que `MAX_SNAPSHOT_LEN` ou que a capacidade da réplica
+    CapacityExceeded,
+    /// Nenhum snapshot gravado
+    Empty,
+    /// Menos réplicas em acordo do que o exigido
+    Disagreement { agreeing: usize },
+}
+
+/// Meio físico de uma réplica. A imagem lida pode conter bytes além do fim do
+/// snapshot (ex.: páginas apagadas); o cabeçalho delimita o conteúdo.
+pub trait ReplicaBackend {
+    fn capacity(&self) -> usize;
+    fn load(&self) -> Result<Vec<u8>, BackupError>;
+    fn store(&mut self, image: &[u8]) -> Result<(), BackupError>;
+}
+
+/// Região de memória
+#[derive(Debug, Clone, Default)]
+pub struct MemoryReplica {
+    region: Vec<u8>,
+}
+
+impl MemoryReplica {
+    pub fn new() -> Self {
+        Self { region: Vec::new() }
+    }
+
+    /// Acesso direto à região (inspeção e injeção de falhas)
+    pub fn region_mut(&mut self) -> &mut [u8] {
+        &mut self.region
+    }
+}
+
+impl ReplicaBackend for MemoryReplica {
+    fn capacity(&self) -> usize {
+        REPLICA_HEADER_LEN + MAX_SNAPSHOT_LEN
+    }
+
+    fn load(&self) -> Result<Vec<u8>, BackupError> {
+        Ok(self.region.clone())
+    }
+
+    fn store(&mut self, image: &[u8]) -> Result<(), BackupError> {
+        self.region.clear();
+        self.region.extend_from_slice(image);
+        Ok(())
+    }
+}
+
+/// Dispositivo flash simulado: apagar leva a página a 0xFF, programar só limpa
+/// bits, e cada página conta seus ciclos de apagamento
+#[derive(Debug, Clone)]
+pub struct SimulatedFlash {
+    cells: Vec<u8>,
+    erase_counts: Vec<u32>,
+}
+
+impl SimulatedFlash {
+    pub fn new(pages: usize) -> Self {
+        Self { cells: vec![0xff; pages * FLASH_PAGE_SIZE], erase_counts: vec![0; pages] }
+    }
+
+    pub fn erase_count(&self, page: usize) -> u32 {
+        self.erase_counts.get(page).copied().unwrap_or(0)
+    }
+
+    /// Inverte um bit armazenado (simula degradação da célula)
+    pub fn flip_bit(&mut self, offset: usize, bit: u8) {
+        if let Some(cell) = self.cells.get_mut(offset) {
+            *cell ^= 1 << (bit & 7);
+        }
+    }
+}
+
+impl ReplicaBackend for SimulatedFlash {
+    fn capacity(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn load(&self) -> Result<Vec<u8>, BackupError> {
+        Ok(self.cells.clone())
+    }
+
+    fn store(&mut self, image: &[u8]) -> Result<(), BackupError> {
+        if image.len() > self.cells.len() {
+            return Err(BackupError::CapacityExceeded);
+        }
+        let pages = image.len().div_ceil(FLASH_PAGE_SIZE);
+        for (page, count) in self.erase_counts.iter_mut().enumerate().take(pages) {
+            self.cells[page * FLASH_PAGE_SIZE..(page + 1) * FLASH_PAGE_SIZE].fill(0xff);
+            *count = count.saturating_add(1);
+        }
+        for (cell, byte) in self.cells.iter_mut().zip(image) {
+            *cell &= *byte;
+        }
+        Ok(())
+    }
+}
+
+/// Arquivo; a escrita vai para `<path>.tmp`, é sincronizada no disco e renomeada
+/// por cima do original, e o diretório é sincronizado para persistir a renomeação
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct FileReplica {
+    path: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl FileReplica {
+    pub fn new<P: Into<std::path::PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ReplicaBackend for FileReplica {
+    fn capacity(&self) -> usize {
+        REPLICA_HEADER_LEN + MAX_SNAPSHOT_LEN
+    }
+
+    fn load(&self) -> Result<Vec<u8>, BackupError> {
+        match std::fs::read(&self.path) {
+            Ok(bytes) => Ok(bytes),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(_) => Err(BackupError::Io),
+        }
+    }
+
+    fn store(&mut self, image: &[u8]) -> Result<(), BackupError> {
+        use std::io::Write;
+
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let mut file = std::fs::File::create(&tmp).map_err(|_| BackupError::Io)?;
+        file.write_all(image).and_then(|()| file.sync_all()).map_err(|_| BackupError::Io)?;
+        drop(file);
+        std::fs::rename(&tmp, &self.path).map_err(|_| BackupError::Io)?;
+        sync_parent(&self.path)
+    }
+}
+
+/// Sincroniza o diretório que contém `path` (entradas renomeadas só são duráveis assim)
+#[cfg(all(feature = "std", unix))]
+fn sync_parent(path: &std::path::Path) -> Result<(), BackupError> {
+    let parent = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => std::path::Path::new("."),
+    };
+    std::fs::File::open(parent).and_then(|dir| dir.sync_all()).map_err(|_| BackupError::Io)
+}
+
+/// Fora de Unix diretórios não podem ser abertos para sincronização
+#[cfg(all(feature = "std", not(unix)))]
+fn sync_parent(_path: &std::path::Path) -> Result<(), BackupError> {
+    Ok(())
+}
+
+/// Resultado de `Hiranyagarbha::repair`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repair {
+    /// Réplicas regravadas com a imagem da maioria
+    pub repaired: usize,
+    /// Geração mais nova que a da maioria, presente só em réplica minoritária
+    /// (snapshot aceito por uma única réplica) e sobrescrita pelo reparo
+    pub discarded: Option<u64>,
+}
+
+/// Estado de uma réplica após leitura e conferência do checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplicaStatus {
+    Valid { generation: u64, checksum: [u8; 32] },
+    /// Nunca gravada
+    Empty,
+    /// Cabeçalho ou checksum inválido
+    Corrupt,
+    Unreadable,
+}
+
+fn checksum(generation: u64, data: &[u8]) -> [u8; 32] {
+    let mut hasher = crypto::Sha256::new();
+    hasher.update(BACKUP_DOMAIN);
+    hasher.update(&generation.to_le_bytes());
+    hasher.update(&(data.len() as u32).to_le_bytes());
+    hasher.update(data);
+    hasher.finalize()
+}
+
+fn encode(generation: u64, data: &[u8]) -> Vec<u8> {
+    let mut image = Vec::with_capacity(REPLICA_HEADER_LEN + data.len());
+    image.extend_from_slice(&REPLICA_MAGIC);
+    image.extend_from_slice(&generation.to_le_bytes());
+    image.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    image.extend_from_slice(&checksum(generation, data));
+    image.extend_from_slice(data);
+    image
+}
+
+/// Geração, checksum e conteúdo de uma imagem íntegra
+fn decode(image: &[u8]) -> Result<(u64, [u8; 32], &[u8]), ReplicaStatus> {
+    if image.len() < REPLICA_HEADER_LEN {
+        return Err(if image.iter().all(|b| *b == 0xff) { ReplicaStatus::Empty } else { ReplicaStatus::Corrupt });
+    }
+    if image[..4] != REPLICA_MAGIC {
+        let blank = image[..REPLICA_HEADER_LEN].iter().all(|b| *b == 0xff);
+        return Err(if blank { ReplicaStatus::Empty } else { ReplicaStatus::Corrupt });
+    }
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&image[4..12]);
+    let generation = u64::from_le_bytes(word);
+    let mut half = [0u8; 4];
+    half.copy_from_slice(&image[12..16]);
+    let len = u32::from_le_bytes(half) as usize;
+    let end = REPLICA_HEADER_LEN.saturating_add(len);
+    if len > MAX_SNAPSHOT_LEN || end > image.len() {
+        return Err(ReplicaStatus::Corrupt);
+    }
+    let data = &image[REPLICA_HEADER_LEN..end];
+    let expected = checksum(generation, data);
+    if !crypto::ct_eq(&expected, &image[16..48]) {
+        return Err(ReplicaStatus::Corrupt);
+    }
+    Ok((generation, expected, data))
+}
+
+/// Armazenamento triplicado. `B` é o meio das réplicas; por padrão, memória.
+pub struct Hiranyagarbha<B: ReplicaBackend = MemoryReplica> {
+    replicas: [B; REPLICAS],
+    generation: u64,
+}
+
+impl Default for Hiranyagarbha<MemoryReplica> {
+    fn default() -> Self {
+        Self::new([MemoryReplica::new(), MemoryReplica::new(), MemoryReplica::new()])
+    }
+}
+
+impl<B: ReplicaBackend> Hiranyagarbha<B> {
+    /// Adota as réplicas no estado em que estão; a geração corrente é a maior
+    /// entre as réplicas íntegras
+    pub fn new(replicas: [B; REPLICAS]) -> Self {
+        let mut store = Self { replicas, generation: 0 };
+        for status in store.inspect() {
+            if let ReplicaStatus::Valid { generation, .. } = status {
+                store.generation = store.generation.max(generation);
+            }
+        }
+        store
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn replicas(&self) -> &[B; REPLICAS] {
+        &self.replicas
+    }
+
+    pub fn replicas_mut(&mut self) -> &mut [B; REPLICAS] {
+        &mut self.replicas
+    }
+
+    /// Grava um novo snapshot nas três réplicas; devolve a geração gravada.
+    /// Falha de qualquer réplica é reportada, mas as demais são gravadas.
+    pub fn snapshot(&mut self, state: &[u8]) -> Result<u64, BackupError> {
+        if state.len() > MAX_SNAPSHOT_LEN {
+            return Err(BackupError::CapacityExceeded);
+        }
+        let generation = self.generation.checked_add(1).ok_or(BackupError::CapacityExceeded)?;
+        let image = encode(generation, state);
+        if self.replicas.iter().any(|r| r.capacity() < image.len()) {
+            return Err(BackupError::CapacityExceeded);
+        }
+        self.generation = generation;
+        let mut result = Ok(generation);
+        for replica in &mut self.replicas {
+            if let Err(e) = replica.store(&image) {
+                result = Err(e);
+            }
+        }
+        result
+    }
+
+    pub fn inspect(&self) -> [ReplicaStatus; REPLICAS] {
+        let mut statuses = [ReplicaStatus::Empty; REPLICAS];
+        for (status, replica) in statuses.iter_mut().zip(&self.replicas) {
+            *status = match replica.load() {
+                Ok(image) => match decode(&image) {
+                    Ok((generation, checksum, _)) => ReplicaStatus::Valid { generation, checksum },
+                    Err(status) => status,
+                },
+                Err(_) => ReplicaStatus::Unreadable,
+            };
+        }
+        statuses
+    }
+
+    /// Gate 5: as três réplicas íntegras, na mesma geração e com o mesmo conteúdo
+    pub fn verify_triplicate(&self) -> Result<u64, BackupError> {
+        let statuses = self.inspect();
+        if statuses.iter().all(|s| *s == ReplicaStatus::Empty) {
+            return Err(BackupError::Empty);
+        }
+        let (index, agreeing) = majority(&statuses);
+        match statuses[index] {
+            ReplicaStatus::Valid { generation, .. } if agreeing == REPLICAS => Ok(generation),
+            _ => Err(BackupError::Disagreement { agreeing }),
+        }
+    }
+
+    /// Conteúdo acordado por pelo menos `MAJORITY` réplicas
+    pub fn restore(&self) -> Result<Vec<u8>, BackupError> {
+        let (generation, image) = self.consensus_image()?;
+        match decode(&image) {
+            Ok((decoded, _, data)) if decoded == generation => Ok(data.to_vec()),
+            _ => Err(BackupError::Io),
+        }
+    }
+
+    /// Regrava as réplicas divergentes com a imagem da maioria; devolve quantas
+    /// foram reparadas e a geração mais nova que o reparo descartou, se houver.
+    /// A geração corrente não recua, para que o número descartado não seja reusado.
+    pub fn repair(&mut self) -> Result<Repair, BackupError> {
+        let statuses = self.inspect();
+        let (generation, image) = self.consensus_image()?;
+        let (index, _) = majority(&statuses);
+        let agreed = statuses[index];
+        let mut report = Repair { repaired: 0, discarded: None };
+        for (replica, status) in self.replicas.iter_mut().zip(statuses) {
+            if status == agreed {
+                continue;
+            }
+            if let ReplicaStatus::Valid { generation: lost, .. } = status {
+                if lost > generation {
+                    report.discarded = report.discarded.max(Some(lost));
+                }
+            }
+            replica.store(&image)?;
+            report.repaired += 1;
+        }
+        self.generation = self.generation.max(generation);
+        Ok(report)
+    }
+
+    /// Geração e imagem canônica do voto majoritário
+    fn consensus_image(&self) -> Result<(u64, Vec<u8>), BackupError> {
+        let statuses = self.inspect();
+        if statuses.iter().all(|s| *s == ReplicaStatus::Empty) {
+            return Err(BackupError::Empty);
+        }
+        let (index, agreeing) = majority(&statuses);
+        let generation = match statuses[index] {
+            ReplicaStatus::Valid { generation, .. } if agreeing >= MAJORITY => generation,
+            _ => return Err(BackupError::Disagreement { agreeing }),
+        };
+        let image = self.replicas[index].load()?;
+        let (_, _, data) = decode(&image).map_err(|_| BackupError::Io)?;
+        Ok((generation, encode(generation, data)))
+    }
+}
+
+/// Réplica íntegra com mais pares idênticos e o tamanho do grupo (0 se nenhuma íntegra)
+fn majority(statuses: &[ReplicaStatus; REPLICAS]) -> (usize, usize) {
+    let mut best = (0, 0);
+    for (index, status) in statuses.iter().enumerate() {
+        if !matches!(status, ReplicaStatus::Valid { .. }) {
+            continue;
+        }
+        let votes = statuses.iter().filter(|s| *s == status).count();
+        if votes > best.1 {
+            best = (index, votes);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flash_store() -> Hiranyagarbha<SimulatedFlash> {
+        Hiranyagarbha::new([SimulatedFlash::new(2), SimulatedFlash::new(2), SimulatedFlash::new(2)])
+    }
+
+    #[test]
+    fn majority_outvotes_a_corrupt_replica_and_repair_rewrites_it() {
+        let mut store = Hiranyagarbha::default();
+        assert_eq!(store.verify_triplicate(), Err(BackupError::Empty));
+        assert_eq!(store.snapshot(b"state"), Ok(1));
+        assert_eq!(store.verify_triplicate(), Ok(1));
+
+        store.replicas_mut()[2].region_mut()[REPLICA_HEADER_LEN] ^= 1;
+        assert_eq!(store.inspect()[2], ReplicaStatus::Corrupt);
+        assert_eq!(store.verify_triplicate(), Err(BackupError::Disagreement { agreeing: 2 }));
+        assert_eq!(store.restore().as_deref(), Ok(&b"state"[..]));
+
+        assert_eq!(store.repair(), Ok(Repair { repaired: 1, discarded: None }));
+        assert_eq!(store.verify_triplicate(), Ok(1));
+
+        // Sem maioria não há conteúdo a restaurar nem a propagar
+        store.replicas_mut()[0].region_mut()[REPLICA_HEADER_LEN] ^= 1;
+        store.replicas_mut()[1].region_mut()[0] ^= 1;
+        assert_eq!(store.restore(), Err(BackupError::Disagreement { agreeing: 1 }));
+        assert_eq!(store.repair(), Err(BackupError::Disagreement { agreeing: 1 }));
+    }
+
+    #[test]
+    fn repair_reports_a_generation_held_by_a_single_replica() {
+        let mut store = Hiranyagarbha::default();
+        store.snapshot(b"old").unwrap();
+        let previous = store.replicas()[0].load().unwrap();
+        store.snapshot(b"new").unwrap();
+        // Duas réplicas não aceitaram a geração 2 e ficaram com a 1
+        for replica in &mut store.replicas_mut()[1..] {
+            replica.store(&previous).unwrap();
+        }
+
+        assert_eq!(store.repair(), Ok(Repair { repaired: 1, discarded: Some(2) }));
+        assert_eq!(store.restore().as_deref(), Ok(&b"old"[..]));
+        assert_eq!(store.verify_triplicate(), Ok(1));
+        assert_eq!(store.snapshot(b"next"), Ok(3));
+    }
+
+    #[test]
+    fn simulated_flash_faults_are_detected_and_repaired() {
+        let mut store = flash_store();
+        assert_eq!(store.inspect(), [ReplicaStatus::Empty; REPLICAS]);
+        store.snapshot(&[0x5a; 300]).unwrap();
+        for replica in store.replicas() {
+            assert_eq!((replica.erase_count(0), replica.erase_count(1)), (1, 1));
+        }
+
+        // Bit invertido no conteúdo e no cabeçalho
+        store.replicas_mut()[0].flip_bit(REPLICA_HEADER_LEN + 10, 3);
+        assert_eq!(store.inspect()[0], ReplicaStatus::Corrupt);
+        store.replicas_mut()[1].flip_bit(1, 0);
+        assert_eq!(store.verify_triplicate(), Err(BackupError::Disagreement { agreeing: 1 }));
+        store.replicas_mut()[1].flip_bit(1, 0);
+        assert_eq!(store.repair(), Ok(Repair { repaired: 1, discarded: None }));
+        assert_eq!(store.verify_triplicate(), Ok(1));
+        assert_eq!(store.replicas()[0].erase_count(0), 2);
+
+        // Snapshot maior que o dispositivo é recusado sem tocar nas réplicas
+        assert_eq!(store.snapshot(&[0; 2 * FLASH_PAGE_SIZE]), Err(BackupError::CapacityExceeded));
+        assert_eq!(store.verify_triplicate(), Ok(1));
+        let mut flash = SimulatedFlash::new(1);
+        assert_eq!(flash.store(&[0; FLASH_PAGE_SIZE + 1]), Err(BackupError::CapacityExceeded));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_replicas_survive_reopening() {
+        extern crate std;
+
+        let dir = std::env::temp_dir().join(std::format!("sasc-backup-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let replicas = || [0, 1, 2].map(|i| FileReplica::new(dir.join(std::format!("replica{i}"))));
+        let mut store = Hiranyagarbha::new(replicas());
+        store.snapshot(b"durable").unwrap();
+        assert!(!dir.join("replica0.tmp").exists());
+
+        let reopened = Hiranyagarbha::new(replicas());
+        assert_eq!(reopened.generation(), 1);
+        assert_eq!(reopened.restore().as_deref(), Ok(&b"durable"[..]));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
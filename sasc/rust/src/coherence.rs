@@ -0,0 +1,332 @@
+//! Sombra do Próton: medição de coerência Φ sobre janela deslizante
+//! Terceira perna da verificação Príncipe / Cardinal / Vajra, alimentada por amostras.
+//! A janela fica atrás do `Mutex` do crate: a sombra é compartilhada pela fronteira
+//! e alimentada concorrentemente por `push(&self)`.
+
+use crate::access::{AccessRequest, Operations};
+use crate::gates::{PHI_CRITICAL, TMR_VARIANCE_MAX};
+use crate::sync::{LockError, Mutex};
+
+/// Tamanho fixo da janela deslizante (sem alocação)
+pub const COHERENCE_WINDOW: usize = 64;
+
+/// Amostras mínimas para avaliação estrita (escritas, dados confidenciais)
+pub const STRICT_MIN_SAMPLES: usize = 8;
+
+/// Margem de Φ abaixo de `PHI_CRITICAL` exigida de escritas
+pub const WRITE_HEADROOM: f64 = 0.02;
+
+/// Margem adicional por nível de `Sensitivity` acima de `Public`
+pub const SENSITIVITY_HEADROOM: f64 = 0.01;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoherenceError {
+    /// Amostra NaN ou infinita
+    NonFinite,
+    Lock(LockError),
+}
+
+/// Veredito da medição
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoherenceStatus {
+    Stable,
+    Unstable,
+    /// Histórico insuficiente para o critério aplicável: o pedido é recusado,
+    /// mas a ausência de dados não é tratada como instabilidade
+    WarmingUp,
+}
+
+/// Métricas da janela corrente
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoherenceMetrics {
+    /// Φ médio da janela (NaN sem amostras ou com a janela indisponível)
+    pub phi: f64,
+    /// Variância populacional de Φ na janela
+    pub variance: f64,
+    /// Inclinação por amostra (mínimos quadrados); positiva = Φ subindo
+    pub trend: f64,
+    pub samples: usize,
+    /// Critério estrito: exige histórico mínimo e Φ projetado abaixo do limiar
+    pub strict: bool,
+    /// Margem abaixo de `PHI_CRITICAL` exigida pela operação e sensibilidade pedidas
+    pub headroom: f64,
+    /// Assinatura do requisitante verificada (verdadeiro em medições sem requisição)
+    pub authenticated: bool,
+    /// Falso quando a janela não pôde ser lida (lock envenenado ou ocupado): falha fechada
+    pub available: bool,
+}
+
+impl CoherenceMetrics {
+    /// Limiar efetivo de Φ para a requisição medida
+    pub fn limit(&self) -> f64 {
+        PHI_CRITICAL - self.headroom
+    }
+
+    /// Amostras exigidas pelo critério aplicável
+    pub fn required_samples(&self) -> usize {
+        if self.strict { STRICT_MIN_SAMPLES } else { 1 }
+    }
+
+    pub fn status(&self) -> CoherenceStatus {
+        if !self.available || !self.authenticated {
+            return CoherenceStatus::Unstable;
+        }
+        if self.samples < self.required_samples() {
+            return CoherenceStatus::WarmingUp;
+        }
+        if self.phi.is_nan() || self.phi >= self.limit() || self.variance > TMR_VARIANCE_MAX {
+            return CoherenceStatus::Unstable;
+        }
+        if self.strict && self.phi + self.trend * self.samples as f64 >= self.limit() {
+            return CoherenceStatus::Unstable;
+        }
+        CoherenceStatus::Stable
+    }
+
+    pub fn stable(&self) -> bool {
+        self.status() == CoherenceStatus::Stable
+    }
+
+    pub fn warming_up(&self) -> bool {
+        self.status() == CoherenceStatus::WarmingUp
+    }
+}
+
+/// Janela deslizante em anel
+struct Window {
+    samples: [f64; COHERENCE_WINDOW],
+    len: usize,
+    next: usize,
+    total: u64,
+}
+
+impl Window {
+    /// Amostra `i` em ordem cronológica (0 = mais antiga retida)
+    fn sample(&self, i: usize) -> f64 {
+        let start = (self.next + COHERENCE_WINDOW - self.len) % COHERENCE_WINDOW;
+        self.samples[(start + i) % COHERENCE_WINDOW]
+    }
+}
+
+pub struct ProtonShadow {
+    window: Mutex<Window>,
+}
+
+impl Default for ProtonShadow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProtonShadow {
+    pub const fn new() -> Self {
+        Self { window: Mutex::new(Window { samples: [0.0; COHERENCE_WINDOW], len: 0, next: 0, total: 0 }) }
+    }
+
+    /// Insere uma leitura de Φ, descartando a mais antiga quando a janela está cheia
+    pub fn push(&self, phi: f64) -> Result<(), CoherenceError> {
+        if !phi.is_finite() {
+            return Err(CoherenceError::NonFinite);
+        }
+        let mut window = self.window.lock().map_err(CoherenceError::Lock)?;
+        let next = window.next;
+        window.samples[next] = phi;
+        window.next = (next + 1) % COHERENCE_WINDOW;
+        if window.len < COHERENCE_WINDOW {
+            window.len += 1;
+        }
+        window.total = window.total.saturating_add(1);
+        Ok(())
+    }
+
+    /// Total de amostras recebidas desde a criação (0 com a janela indisponível)
+    pub fn total_samples(&self) -> u64 {
+        self.window.lock().map_or(0, |window| window.total)
+    }
+
+    pub fn clear(&self) -> Result<(), CoherenceError> {
+        let mut window = self.window.lock().map_err(CoherenceError::Lock)?;
+        window.len = 0;
+        window.next = 0;
+        Ok(())
+    }
+
+    /// Métricas da janela sob critério normal
+    pub fn snapshot(&self) -> CoherenceMetrics {
+        let empty = CoherenceMetrics {
+            phi: f64::NAN,
+            variance: f64::NAN,
+            trend: 0.0,
+            samples: 0,
+            strict: false,
+            headroom: 0.0,
+            authenticated: true,
+            available: true,
+        };
+        let window = match self.window.lock() {
+            Ok(window) => window,
+            Err(_) => return CoherenceMetrics { available: false, ..empty },
+        };
+        let n = window.len;
+        if n == 0 {
+            return empty;
+        }
+        let nf = n as f64;
+        let mut sum = 0.0;
+        for i in 0..n {
+            sum += window.sample(i);
+        }
+        let mean = sum / nf;
+
+        // Variância e regressão linear em torno das médias (x = índice da amostra)
+        let x_mean = (nf - 1.0) / 2.0;
+        let mut var = 0.0;
+        let mut sxy = 0.0;
+        let mut sxx = 0.0;
+        for i in 0..n {
+            let dy = window.sample(i) - mean;
+            let dx = i as f64 - x_mean;
+            var += dy * dy;
+            sxy += dx * dy;
+            sxx += dx * dx;
+        }
+
+        CoherenceMetrics {
+            phi: mean,
+            variance: var / nf,
+            trend: if sxx > 0.0 { sxy / sxx } else { 0.0 },
+            samples: n,
+            strict: false,
+            headroom: 0.0,
+            authenticated: true,
+            available: true,
+        }
+    }
+
+    /// Mede a coerência para uma requisição: escritas e dados confidenciais
+    /// são avaliados sob critério estrito e com margem proporcional ao risco;
+    /// requisitante sem assinatura válida nunca é estável
+    pub fn measure_coherence(&self, request: &AccessRequest) -> CoherenceMetrics {
+        let mut headroom = SENSITIVITY_HEADROOM * request.sensitivity.code() as f64;
+        if request.operations.contains(Operations::WRITE) {
+            headroom += WRITE_HEADROOM;
+        }
+        CoherenceMetrics {
+            strict: request.requires_deliberation(),
+            headroom,
+            authenticated: request.authenticated(),
+            ..self.snapshot()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::access::{Purpose, Sensitivity};
+    use crate::crypto::SigningKey;
+    use alloc::string::String;
+
+    fn shadow(phi: f64) -> ProtonShadow {
+        let shadow = ProtonShadow::new();
+        for _ in 0..STRICT_MIN_SAMPLES {
+            shadow.push(phi).unwrap();
+        }
+        shadow
+    }
+
+    fn request(operations: Operations, sensitivity: Sensitivity) -> AccessRequest {
+        let key = SigningKey::from_seed(&[3; 32]);
+        AccessRequest::sign(&key, Purpose::Maintenance, operations, sensitivity, String::from("calibração"))
+    }
+
+    #[test]
+    fn operation_and_sensitivity_narrow_the_limit() {
+        let shadow = shadow(0.70);
+        assert!(shadow.measure_coherence(&request(Operations::READ, Sensitivity::Public)).stable());
+        assert!(shadow.measure_coherence(&request(Operations::READ, Sensitivity::Internal)).stable());
+        assert!(!shadow.measure_coherence(&request(Operations::READ, Sensitivity::Confidential)).stable());
+        assert!(!shadow.measure_coherence(&request(Operations::WRITE, Sensitivity::Public)).stable());
+        let sovereign = shadow.measure_coherence(&request(Operations::READ_WRITE, Sensitivity::Sovereign));
+        assert!((sovereign.limit() - (PHI_CRITICAL - 0.05)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn unauthenticated_requester_is_never_stable() {
+        let shadow = shadow(0.5);
+        let mut forged = request(Operations::READ, Sensitivity::Public);
+        assert!(shadow.measure_coherence(&forged).stable());
+        forged.requester = SigningKey::from_seed(&[4; 32]).public_key();
+        assert!(!forged.authenticated());
+        assert!(!shadow.measure_coherence(&forged).stable());
+        let mut altered = request(Operations::READ, Sensitivity::Public);
+        altered.operations = Operations::READ_WRITE;
+        assert!(!shadow.measure_coherence(&altered).stable());
+    }
+
+    #[test]
+    fn rising_trend_fails_strict_evaluation() {
+        let shadow = ProtonShadow::new();
+        for i in 0..STRICT_MIN_SAMPLES {
+            shadow.push(0.69 + 0.001 * i as f64).unwrap();
+        }
+        let metrics = shadow.measure_coherence(&request(Operations::READ, Sensitivity::Confidential));
+        assert!(metrics.strict && metrics.trend > 0.0);
+        assert!(!metrics.stable());
+        assert!(shadow.snapshot().stable());
+    }
+
+    #[test]
+    fn non_finite_samples_are_refused() {
+        let shadow = ProtonShadow::new();
+        assert_eq!(shadow.push(f64::NAN), Err(CoherenceError::NonFinite));
+        assert_eq!(shadow.push(f64::INFINITY), Err(CoherenceError::NonFinite));
+        assert_eq!(shadow.total_samples(), 0);
+    }
+
+    #[test]
+    fn missing_history_is_warm_up_not_instability() {
+        let shadow = ProtonShadow::new();
+        let key = SigningKey::from_seed(&[3; 32]);
+        let read =
+            AccessRequest::sign(&key, Purpose::Diagnostics, Operations::READ, Sensitivity::Public, String::new());
+        assert_eq!(shadow.measure_coherence(&read).status(), CoherenceStatus::WarmingUp);
+        shadow.push(0.5).unwrap();
+        assert_eq!(shadow.measure_coherence(&read).status(), CoherenceStatus::Stable);
+        let strict = request(Operations::WRITE, Sensitivity::Internal);
+        assert_eq!(shadow.measure_coherence(&strict).status(), CoherenceStatus::WarmingUp);
+        // Sem assinatura válida não há aquecimento: o pedido é instável
+        let mut forged = read.clone();
+        forged.signature[0] ^= 1;
+        assert_eq!(ProtonShadow::new().measure_coherence(&forged).status(), CoherenceStatus::Unstable);
+    }
+
+    #[test]
+    fn shared_shadow_accepts_concurrent_samples() {
+        extern crate std;
+        let shadow = ProtonShadow::new();
+        std::thread::scope(|scope| {
+            for t in 0..4 {
+                let shadow = &shadow;
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        // O giro limitado pode expirar sob contenção: repete
+                        while shadow.push(0.5 + 0.001 * t as f64) == Err(CoherenceError::Lock(LockError::WouldBlock)) {}
+                    }
+                });
+            }
+        });
+        assert_eq!(shadow.total_samples(), 400);
+        assert_eq!(shadow.snapshot().samples, COHERENCE_WINDOW);
+    }
+
+    #[test]
+    fn unavailable_window_fails_closed() {
+        let shadow = shadow(0.5);
+        shadow.window.lock().unwrap().poison();
+        let metrics = shadow.measure_coherence(&request(Operations::READ, Sensitivity::Public));
+        assert!(!metrics.available);
+        assert_eq!(metrics.status(), CoherenceStatus::Unstable);
+        assert!(matches!(shadow.push(0.5), Err(CoherenceError::Lock(LockError::Poisoned))));
+    }
+}
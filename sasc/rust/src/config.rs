@@ -0,0 +1,466 @@
+//! Limiares configuráveis dos 7 Gates
+//! `GateConfig::DEFAULT` reproduz os valores de referência; configurações
+//! alternativas vêm de uma `const` (no_std) ou de texto TOML/JSON plano, e são
+//! validadas antes do uso. O hash da configuração entra em cada `Attestation`.
+
+use crate::crypto;
+use crate::gates::{
+    canonical_bits, COMPTON_VOLUME, CONSENSUS_REQUIRED, ENTROPY_TOLERANCE, FIREWALL_MAX, SPIN_MAX, SPIN_MIN,
+    TARGET_ENTROPY,
+};
+
+/// Domínio de separação do hash de configuração
+pub const CONFIG_DOMAIN: &[u8] = b"SASC-GATECFG-v1";
+
+/// Limite de linhas/entradas de um arquivo de configuração
+pub const MAX_CONFIG_LINES: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    NonFinite { field: &'static str },
+    OutOfRange { field: &'static str },
+    /// `spin_min` não é menor que `spin_max`
+    InvertedRange,
+    /// Texto inválido na posição indicada (a partir de 1)
+    Syntax { line: usize, column: usize },
+    UnknownKey { line: usize },
+    DuplicateKey { line: usize },
+    #[cfg(feature = "std")]
+    Io,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateConfig {
+    /// Gate 1: faixa do spin total (unidades de ℏ)
+    pub spin_min: f64,
+    pub spin_max: f64,
+    /// Gate 2: volume coerente deve exceder o volume Compton (m³)
+    pub compton_volume: f64,
+    /// Gate 3: entropia de emaranhamento alvo e tolerância absoluta
+    pub target_entropy: f64,
+    pub entropy_tolerance: f64,
+    /// Gate 4: expansão máxima do firewall
+    pub firewall_max: f64,
+    /// Gate 6: fração mínima de consenso cardinal
+    pub consensus_required: f64,
+}
+
+impl Default for GateConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Chaves aceitas, na ordem canônica do hash
+const KEYS: [&str; 7] = [
+    "spin_min",
+    "spin_max",
+    "compton_volume",
+    "target_entropy",
+    "entropy_tolerance",
+    "firewall_max",
+    "consensus_required",
+];
+
+impl GateConfig {
+    pub const DEFAULT: GateConfig = GateConfig {
+        spin_min: SPIN_MIN,
+        spin_max: SPIN_MAX,
+        compton_volume: COMPTON_VOLUME,
+        target_entropy: TARGET_ENTROPY,
+        entropy_tolerance: ENTROPY_TOLERANCE,
+        firewall_max: FIREWALL_MAX,
+        consensus_required: CONSENSUS_REQUIRED,
+    };
+
+    fn values(&self) -> [f64; 7] {
+        [
+            self.spin_min,
+            self.spin_max,
+            self.compton_volume,
+            self.target_entropy,
+            self.entropy_tolerance,
+            self.firewall_max,
+            self.consensus_required,
+        ]
+    }
+
+    fn slot(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.spin_min,
+            1 => &mut self.spin_max,
+            2 => &mut self.compton_volume,
+            3 => &mut self.target_entropy,
+            4 => &mut self.entropy_tolerance,
+            5 => &mut self.firewall_max,
+            _ => &mut self.consensus_required,
+        }
+    }
+
+    /// Validação utilizável em contexto `const`:
+    /// `const _: () = assert!(MINHA_CONFIG.validate().is_ok());`
+    pub const fn validate(&self) -> Result<(), ConfigError> {
+        let values = [
+            self.spin_min,
+            self.spin_max,
+            self.compton_volume,
+            self.target_entropy,
+            self.entropy_tolerance,
+            self.firewall_max,
+            self.consensus_required,
+        ];
+        let mut i = 0;
+        while i < values.len() {
+            if !values[i].is_finite() {
+                return Err(ConfigError::NonFinite { field: KEYS[i] });
+            }
+            i += 1;
+        }
+        if self.spin_min <= 0.0 {
+            return Err(ConfigError::OutOfRange { field: "spin_min" });
+        }
+        if self.spin_min >= self.spin_max {
+            return Err(ConfigError::InvertedRange);
+        }
+        if self.compton_volume <= 0.0 {
+            return Err(ConfigError::OutOfRange { field: "compton_volume" });
+        }
+        if self.target_entropy <= 0.0 {
+            return Err(ConfigError::OutOfRange { field: "target_entropy" });
+        }
+        if self.entropy_tolerance <= 0.0 || self.entropy_tolerance >= self.target_entropy {
+            return Err(ConfigError::OutOfRange { field: "entropy_tolerance" });
+        }
+        if self.firewall_max <= 0.0 || self.firewall_max > 1.0 {
+            return Err(ConfigError::OutOfRange { field: "firewall_max" });
+        }
+        if self.consensus_required <= 0.0 || self.consensus_required > 1.0 {
+            return Err(ConfigError::OutOfRange { field: "consensus_required" });
+        }
+        Ok(())
+    }
+
+    /// Hash canônico: SHA-256 de domínio ‖ bits IEEE-754 (LE) de cada campo na ordem de `KEYS`,
+    /// com NaN e -0.0 canonizados como em `TemporalMetrics::encode`
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = crypto::Sha256::new();
+        hasher.update(CONFIG_DOMAIN);
+        for value in self.values() {
+            hasher.update(&canonical_bits(value).to_le_bytes());
+        }
+        hasher.finalize()
+    }
+
+    /// TOML plano: `chave = número` por linha, `#` comenta, tabela `[gates]` opcional.
+    /// Chaves ausentes mantêm o valor padrão.
+    pub fn from_toml(source: &str) -> Result<Self, ConfigError> {
+        let mut config = Self::DEFAULT;
+        let mut seen = [false; KEYS.len()];
+        for (index, raw) in source.lines().enumerate() {
+            let line = index + 1;
+            if line > MAX_CONFIG_LINES {
+                return Err(ConfigError::Syntax { line, column: 1 });
+            }
+            let text = raw.split('#').next().unwrap_or("");
+            let trimmed = text.trim();
+            if trimmed.is_empty() || trimmed == "[gates]" {
+                continue;
+            }
+            let Some((key, value)) = text.split_once('=') else {
+                return Err(ConfigError::Syntax { line, column: column_of(raw, trimmed) });
+            };
+            let key = key.trim();
+            let value_text = value.trim();
+            let field = KEYS.iter().position(|k| *k == key).ok_or(ConfigError::UnknownKey { line })?;
+            if seen[field] {
+                return Err(ConfigError::DuplicateKey { line });
+            }
+            seen[field] = true;
+            *config.slot(field) = parse_number(value_text)
+                .ok_or(ConfigError::Syntax { line, column: column_of(raw, value_text) })?;
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// JSON plano: um objeto `{ "chave": número, ... }`, sem aninhamento
+    pub fn from_json(source: &str) -> Result<Self, ConfigError> {
+        let mut config = Self::DEFAULT;
+        let mut seen = [false; KEYS.len()];
+        let mut lexer = JsonLexer { source, bytes: source.as_bytes(), pos: 0 };
+
+        lexer.expect(b'{')?;
+        lexer.skip_whitespace();
+        if lexer.peek() == Some(b'}') {
+            lexer.pos += 1;
+        } else {
+            for _ in 0..MAX_CONFIG_LINES {
+                let (line, _) = lexer.position();
+                let key = lexer.string()?;
+                lexer.expect(b':')?;
+                let value = lexer.number()?;
+                let field = KEYS.iter().position(|k| *k == key).ok_or(ConfigError::UnknownKey { line })?;
+                if seen[field] {
+                    return Err(ConfigError::DuplicateKey { line });
+                }
+                seen[field] = true;
+                *config.slot(field) = value;
+
+                lexer.skip_whitespace();
+                match lexer.peek() {
+                    Some(b',') => lexer.pos += 1,
+                    Some(b'}') => {
+                        lexer.pos += 1;
+                        break;
+                    }
+                    _ => return Err(lexer.error()),
+                }
+            }
+        }
+        lexer.skip_whitespace();
+        if lexer.pos != lexer.bytes.len() {
+            return Err(lexer.error());
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_toml_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|_| ConfigError::Io)?;
+        Self::from_toml(&text)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_json_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|_| ConfigError::Io)?;
+        Self::from_json(&text)
+    }
+}
+
+/// Coluna (a partir de 1) de um trecho dentro da linha
+fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line[..offset.min(line.len())].chars().count() + 1
+}
+
+/// Número decimal finito (`_` como separador, como em TOML)
+fn parse_number(text: &str) -> Option<f64> {
+    let valid = !text.is_empty()
+        && text.bytes().all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-' | b'_'))
+        && text.bytes().any(|b| b.is_ascii_digit());
+    if !valid {
+        return None;
+    }
+    let mut buffer = [0u8; 64];
+    let mut len = 0;
+    for b in text.bytes().filter(|b| *b != b'_') {
+        *buffer.get_mut(len)? = b;
+        len += 1;
+    }
+    let digits = core::str::from_utf8(&buffer[..len]).ok()?;
+    digits.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+struct JsonLexer<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> JsonLexer<'a> {
+    fn position(&self) -> (usize, usize) {
+        let before = &self.source[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+        (line, column)
+    }
+
+    fn error(&self) -> ConfigError {
+        let (line, column) = self.position();
+        ConfigError::Syntax { line, column }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ConfigError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(self.error());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// String sem escapes (as chaves aceitas são ASCII simples)
+    fn string(&mut self) -> Result<&'a str, ConfigError> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        while self.pos < self.bytes.len() && self.bytes[self.pos] != b'"' {
+            if self.bytes[self.pos] == b'\\' || self.bytes[self.pos] == b'\n' {
+                return Err(self.error());
+            }
+            self.pos += 1;
+        }
+        if self.pos >= self.bytes.len() {
+            return Err(self.error());
+        }
+        let text = &self.source[start..self.pos];
+        self.pos += 1;
+        Ok(text)
+    }
+
+    fn number(&mut self) -> Result<f64, ConfigError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.pos < self.bytes.len()
+            && (self.bytes[self.pos].is_ascii_digit() || matches!(self.bytes[self.pos], b'.' | b'e' | b'E' | b'+' | b'-'))
+        {
+            self.pos += 1;
+        }
+        let text = &self.source[start..self.pos];
+        match text.parse::<f64>() {
+            Ok(value) if value.is_finite() && !text.is_empty() => Ok(value),
+            _ => {
+                self.pos = start;
+                Err(self.error())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn hash_is_sha256_over_the_canonical_fields() {
+        let mut expected = Vec::new();
+        expected.extend_from_slice(CONFIG_DOMAIN);
+        for value in GateConfig::DEFAULT.values() {
+            expected.extend_from_slice(&value.to_bits().to_le_bytes());
+        }
+        assert_eq!(GateConfig::DEFAULT.hash(), crypto::sha256(&expected));
+
+        let mut looser = GateConfig::DEFAULT;
+        looser.firewall_max = 0.95;
+        assert_ne!(looser.hash(), GateConfig::DEFAULT.hash());
+
+        // NaN com payload e -0.0 codificam como o NaN canônico e +0.0
+        let with = |compton_volume| GateConfig { compton_volume, ..GateConfig::DEFAULT }.hash();
+        assert_eq!(with(f64::from_bits(0x7ff8_0000_0000_0001)), with(f64::NAN));
+        assert_eq!(with(-f64::NAN), with(f64::NAN));
+        assert_eq!(with(-0.0), with(0.0));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn default_keeps_the_original_thresholds() {
+        let original = GateConfig {
+            spin_min: 0.99,
+            spin_max: 1.01,
+            compton_volume: 3.896e-47,
+            target_entropy: 0.69314718056,
+            entropy_tolerance: 0.0001,
+            firewall_max: 0.90,
+            consensus_required: 1.0,
+        };
+        assert_eq!(GateConfig::DEFAULT, original);
+        assert_eq!(GateConfig::default(), original);
+        assert_eq!(GateConfig::DEFAULT.validate(), Ok(()));
+    }
+
+    #[test]
+    fn toml_overrides_listed_keys() {
+        let source = "[gates]\n# mais largo\nspin_min = 0.98  # ℏ\nfirewall_max = 0.9_5\n";
+        let config = GateConfig::from_toml(source).unwrap();
+        assert_eq!(config, GateConfig { spin_min: 0.98, firewall_max: 0.95, ..GateConfig::DEFAULT });
+        assert_eq!(GateConfig::from_toml(""), Ok(GateConfig::DEFAULT));
+
+        assert_eq!(GateConfig::from_toml("\nspin = 1.0"), Err(ConfigError::UnknownKey { line: 2 }));
+        assert_eq!(GateConfig::from_toml("firewall_max = high"), Err(ConfigError::Syntax { line: 1, column: 16 }));
+        assert_eq!(GateConfig::from_toml("firewall_max = inf"), Err(ConfigError::Syntax { line: 1, column: 16 }));
+        assert_eq!(GateConfig::from_toml("  firewall_max 0.9"), Err(ConfigError::Syntax { line: 1, column: 3 }));
+        assert_eq!(
+            GateConfig::from_toml("spin_min = 0.98\nspin_min = 0.97"),
+            Err(ConfigError::DuplicateKey { line: 2 })
+        );
+        assert_eq!(
+            GateConfig::from_toml("firewall_max = 1.5"),
+            Err(ConfigError::OutOfRange { field: "firewall_max" })
+        );
+    }
+
+    #[test]
+    fn json_overrides_listed_keys() {
+        let config = GateConfig::from_json("{\"spin_min\": 0.98,\n \"firewall_max\": 9.5e-1}").unwrap();
+        assert_eq!(config, GateConfig { spin_min: 0.98, firewall_max: 0.95, ..GateConfig::DEFAULT });
+        assert_eq!(GateConfig::from_json(" { } "), Ok(GateConfig::DEFAULT));
+
+        assert_eq!(GateConfig::from_json("{\n\"spin\": 1.0}"), Err(ConfigError::UnknownKey { line: 2 }));
+        assert_eq!(GateConfig::from_json("{\"firewall_max\": high}"), Err(ConfigError::Syntax { line: 1, column: 18 }));
+        assert_eq!(GateConfig::from_json("{} x"), Err(ConfigError::Syntax { line: 1, column: 4 }));
+        assert_eq!(
+            GateConfig::from_json("{\"spin_min\": 0.98, \"spin_min\": 0.98}"),
+            Err(ConfigError::DuplicateKey { line: 1 })
+        );
+        assert_eq!(GateConfig::from_json("{\"spin_max\": 0.5}"), Err(ConfigError::InvertedRange));
+    }
+
+    #[test]
+    fn validate_reports_each_violation() {
+        let cases: [(GateConfig, ConfigError); 12] = [
+            (GateConfig { spin_max: f64::NAN, ..GateConfig::DEFAULT }, ConfigError::NonFinite { field: "spin_max" }),
+            (
+                GateConfig { consensus_required: f64::INFINITY, ..GateConfig::DEFAULT },
+                ConfigError::NonFinite { field: "consensus_required" },
+            ),
+            (GateConfig { spin_min: 0.0, ..GateConfig::DEFAULT }, ConfigError::OutOfRange { field: "spin_min" }),
+            (GateConfig { spin_min: 1.01, ..GateConfig::DEFAULT }, ConfigError::InvertedRange),
+            (
+                GateConfig { compton_volume: 0.0, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "compton_volume" },
+            ),
+            (
+                GateConfig { target_entropy: -1.0, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "target_entropy" },
+            ),
+            (
+                GateConfig { entropy_tolerance: 0.0, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "entropy_tolerance" },
+            ),
+            (
+                GateConfig { entropy_tolerance: 0.7, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "entropy_tolerance" },
+            ),
+            (
+                GateConfig { firewall_max: 0.0, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "firewall_max" },
+            ),
+            (
+                GateConfig { firewall_max: 1.01, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "firewall_max" },
+            ),
+            (
+                GateConfig { consensus_required: 0.0, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "consensus_required" },
+            ),
+            (
+                GateConfig { consensus_required: 1.5, ..GateConfig::DEFAULT },
+                ConfigError::OutOfRange { field: "consensus_required" },
+            ),
+        ];
+        for (config, error) in cases {
+            assert_eq!(config.validate(), Err(error), "{config:?}");
+        }
+    }
+}
@@ -0,0 +1,375 @@
+//! Contenção graduada e protocolo de recuperação
+//! O nível é escolhido pela verificação que falhou e pela distância de Φ aos
+//! limiares constitucionais; o degelo é uma máquina de estados determinística.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::backup::BackupError;
+use crate::gates::{PHI_CRITICAL, PHI_EMERGENCY, PHI_FREEZE};
+
+/// Níveis de contenção em ordem crescente de severidade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Containment {
+    /// Concessões espaçadas e de validade reduzida
+    Throttle,
+    /// Apenas leituras; escritas em curso são recusadas
+    ReadOnly,
+    /// Nenhuma nova concessão; concessões ativas revogadas
+    Quarantine,
+    /// Quarentena e restauração obrigatória a partir do backup Hiranyagarbha
+    RollbackToBackup,
+    /// Congelamento total
+    HardFreeze,
+}
+
+impl Containment {
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            Containment::Throttle => 1,
+            Containment::ReadOnly => 2,
+            Containment::Quarantine => 3,
+            Containment::RollbackToBackup => 4,
+            Containment::HardFreeze => 5,
+        }
+    }
+
+    pub(crate) fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Containment::Throttle),
+            2 => Some(Containment::ReadOnly),
+            3 => Some(Containment::Quarantine),
+            4 => Some(Containment::RollbackToBackup),
+            5 => Some(Containment::HardFreeze),
+            _ => None,
+        }
+    }
+
+    /// Nível mínimo imposto pela coerência Φ. Φ NaN (coerência desconhecida)
+    /// falha fechado em `Quarantine`.
+    pub fn for_phi(phi: f64) -> Option<Self> {
+        if phi.is_nan() {
+            Some(Containment::Quarantine)
+        } else if phi >= PHI_FREEZE {
+            Some(Containment::HardFreeze)
+        } else if phi >= PHI_EMERGENCY {
+            Some(Containment::RollbackToBackup)
+        } else if phi >= PHI_CRITICAL {
+            Some(Containment::Quarantine)
+        } else {
+            None
+        }
+    }
+
+    /// Seleciona a resposta: o mais severo entre as verificações reprovadas e a faixa de Φ
+    pub fn select(failed: FailedChecks, phi: f64) -> Option<Self> {
+        Self::for_phi(phi).max(Self::for_failures(failed))
+    }
+
+    /// Nível imposto apenas pelas verificações reprovadas
+    pub fn for_failures(failed: FailedChecks) -> Option<Self> {
+        let mut level: Option<Containment> = None;
+        let mut escalate = |candidate: Containment| {
+            level = Some(level.map_or(candidate, |l| l.max(candidate)));
+        };
+        if failed.vote {
+            escalate(Containment::Throttle);
+        }
+        if failed.veto {
+            escalate(Containment::ReadOnly);
+        }
+        if failed.coherence {
+            escalate(Containment::Quarantine);
+        }
+        level
+    }
+
+    pub fn permits_writes(self) -> bool {
+        self < Containment::ReadOnly
+    }
+
+    pub fn revokes_grants(self) -> bool {
+        self >= Containment::Quarantine
+    }
+
+    pub fn requires_restore(self) -> bool {
+        self >= Containment::RollbackToBackup
+    }
+}
+
+/// Quais das verificações Príncipe / Cardinal / Vajra reprovaram o pedido
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FailedChecks {
+    pub veto: bool,
+    pub vote: bool,
+    pub coherence: bool,
+}
+
+impl FailedChecks {
+    pub fn any(&self) -> bool {
+        self.veto || self.vote || self.coherence
+    }
+}
+
+/// Sinal de contenção compartilhado entre a fronteira e as concessões ativas.
+/// Só escala; a redução ocorre apenas ao fim do protocolo de recuperação.
+pub struct ContainmentSignal {
+    level: AtomicU8,
+}
+
+impl Default for ContainmentSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContainmentSignal {
+    pub const fn new() -> Self {
+        Self { level: AtomicU8::new(0) }
+    }
+
+    pub fn trigger(&self, level: Containment) {
+        self.level.fetch_max(level.code(), Ordering::SeqCst);
+    }
+
+    pub fn level(&self) -> Option<Containment> {
+        Containment::from_code(self.level.load(Ordering::SeqCst))
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.level().is_some()
+    }
+
+    /// Concessões ativas devem ser revogadas no nível corrente
+    pub fn revokes_grants(&self) -> bool {
+        self.level().is_some_and(Containment::revokes_grants)
+    }
+
+    pub fn permits_writes(&self) -> bool {
+        self.level().is_none_or(Containment::permits_writes)
+    }
+
+    pub(crate) fn clear(&self) {
+        self.level.store(0, Ordering::SeqCst);
+    }
+}
+
+// ============================================================================
+// PROTOCOLO DE RECUPERAÇÃO (DEGELO)
+// ============================================================================
+
+/// Estados do protocolo: contido → degelo pedido → votos confirmados →
+/// veto liberado → (backup restaurado) → normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryState {
+    Normal,
+    Contained { level: Containment },
+    ThawRequested { level: Containment, motion: [u8; 32] },
+    VotesConfirmed { level: Containment },
+    VetoReleased { level: Containment },
+    BackupRestored { level: Containment },
+}
+
+impl RecoveryState {
+    pub fn level(&self) -> Option<Containment> {
+        match *self {
+            RecoveryState::Normal => None,
+            RecoveryState::Contained { level }
+            | RecoveryState::ThawRequested { level, .. }
+            | RecoveryState::VotesConfirmed { level }
+            | RecoveryState::VetoReleased { level }
+            | RecoveryState::BackupRestored { level } => Some(level),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecoveryEvent {
+    /// Nova contenção; durante a recuperação reinicia o protocolo no nível mais severo
+    Trigger(Containment),
+    /// Abertura de uma moção de degelo no Sínodo
+    RequestThaw { motion: [u8; 32] },
+    /// A moção de degelo foi aprovada por votos novos
+    VotePassed { motion: [u8; 32] },
+    /// O Príncipe liberou o veto no escopo de degelo
+    VetoReleased,
+    /// O estado foi restaurado a partir do backup triplicado
+    BackupRestored,
+    /// Retomada com a coerência medida no momento
+    Resume { phi: f64 },
+}
+
+impl RecoveryEvent {
+    /// Código estável do evento, usado na cadeia de auditoria
+    pub fn code(&self) -> u8 {
+        match self {
+            RecoveryEvent::Trigger(_) => 1,
+            RecoveryEvent::RequestThaw { .. } => 2,
+            RecoveryEvent::VotePassed { .. } => 3,
+            RecoveryEvent::VetoReleased => 4,
+            RecoveryEvent::BackupRestored => 5,
+            RecoveryEvent::Resume { .. } => 6,
+        }
+    }
+
+    pub(crate) fn is_code(code: u8) -> bool {
+        (1..=6).contains(&code)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecoveryError {
+    IllegalTransition { from: RecoveryState, event: RecoveryEvent },
+    MotionMismatch,
+    BackupRequired,
+    CoherenceUnstable,
+    /// O evento não foi confirmado pela governança (votos ou veto) ou foi
+    /// declarado sem a verificação correspondente
+    Unverified,
+    /// Estado de recuperação ou dados protegidos com lock envenenado
+    Poisoned,
+    /// Backup sem as três réplicas em acordo ou ilegível
+    Backup(BackupError),
+    /// Conteúdo restaurado não corresponde a um estado válido dos dados
+    UndecodableBackup,
+    /// A transição não pôde ser registrada na cadeia de auditoria
+    Unaudited,
+}
+
+/// Função de transição pura do protocolo de recuperação
+pub fn recovery_step(state: RecoveryState, event: RecoveryEvent) -> Result<RecoveryState, RecoveryError> {
+    use RecoveryEvent as E;
+    use RecoveryState as S;
+
+    match (state, event) {
+        (_, E::Trigger(level)) => Ok(S::Contained {
+            level: state.level().map_or(level, |current| current.max(level)),
+        }),
+        (S::Contained { level }, E::RequestThaw { motion }) => Ok(S::ThawRequested { level, motion }),
+        (S::ThawRequested { level, motion }, E::VotePassed { motion: voted }) => {
+            if motion != voted {
+                return Err(RecoveryError::MotionMismatch);
+            }
+            Ok(S::VotesConfirmed { level })
+        }
+        (S::VotesConfirmed { level }, E::VetoReleased) => Ok(S::VetoReleased { level }),
+        (S::VetoReleased { level }, E::BackupRestored) if level.requires_restore() => {
+            Ok(S::BackupRestored { level })
+        }
+        (S::VetoReleased { level }, E::Resume { .. }) if level.requires_restore() => {
+            Err(RecoveryError::BackupRequired)
+        }
+        (S::VetoReleased { .. }, E::Resume { phi }) | (S::BackupRestored { .. }, E::Resume { phi }) => {
+            if Containment::for_phi(phi).is_some() {
+                return Err(RecoveryError::CoherenceUnstable);
+            }
+            Ok(S::Normal)
+        }
+        (from, event) => Err(RecoveryError::IllegalTransition { from, event }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use RecoveryEvent as E;
+    use RecoveryState as S;
+
+    const MOTION: [u8; 32] = [9; 32];
+
+    /// Percorre o protocolo completo a partir de `Contained { level }`
+    fn walk(level: Containment, events: &[RecoveryEvent]) -> Result<RecoveryState, RecoveryError> {
+        let mut state = S::Contained { level };
+        for event in events {
+            state = recovery_step(state, *event)?;
+        }
+        Ok(state)
+    }
+
+    #[test]
+    fn unknown_phi_fails_closed() {
+        assert_eq!(Containment::for_phi(f64::NAN), Some(Containment::Quarantine));
+        assert_eq!(Containment::for_phi(f64::INFINITY), Some(Containment::HardFreeze));
+        assert_eq!(Containment::for_phi(PHI_CRITICAL - 1e-9), None);
+        let vote = FailedChecks { vote: true, ..FailedChecks::default() };
+        assert_eq!(Containment::select(vote, f64::NAN), Some(Containment::Quarantine));
+        assert_eq!(Containment::select(vote, 0.5), Some(Containment::Throttle));
+        assert_eq!(Containment::select(FailedChecks::default(), 0.5), None);
+    }
+
+    #[test]
+    fn thaw_without_restore() {
+        let events = [E::RequestThaw { motion: MOTION }, E::VotePassed { motion: MOTION }, E::VetoReleased];
+        assert_eq!(walk(Containment::Quarantine, &events), Ok(S::VetoReleased { level: Containment::Quarantine }));
+        let resumed = [&events[..], &[E::Resume { phi: 0.5 }]].concat();
+        assert_eq!(walk(Containment::Quarantine, &resumed), Ok(S::Normal));
+    }
+
+    #[test]
+    fn restore_is_mandatory_from_rollback() {
+        let events = [E::RequestThaw { motion: MOTION }, E::VotePassed { motion: MOTION }, E::VetoReleased];
+        let skipped = [&events[..], &[E::Resume { phi: 0.5 }]].concat();
+        assert_eq!(walk(Containment::RollbackToBackup, &skipped), Err(RecoveryError::BackupRequired));
+        let restored = [&events[..], &[E::BackupRestored, E::Resume { phi: 0.5 }]].concat();
+        assert_eq!(walk(Containment::HardFreeze, &restored), Ok(S::Normal));
+        // Sem nível que exija restauração, o evento é ilegal
+        let needless = [&events[..], &[E::BackupRestored]].concat();
+        assert!(matches!(walk(Containment::Throttle, &needless), Err(RecoveryError::IllegalTransition { .. })));
+    }
+
+    #[test]
+    fn resume_requires_coherence() {
+        let events = [E::RequestThaw { motion: MOTION }, E::VotePassed { motion: MOTION }, E::VetoReleased];
+        for phi in [PHI_CRITICAL, PHI_FREEZE, f64::NAN] {
+            let attempt = [&events[..], &[E::Resume { phi }]].concat();
+            assert_eq!(walk(Containment::Throttle, &attempt), Err(RecoveryError::CoherenceUnstable));
+        }
+    }
+
+    #[test]
+    fn votes_must_match_the_thaw_motion() {
+        let events = [E::RequestThaw { motion: MOTION }, E::VotePassed { motion: [8; 32] }];
+        assert_eq!(walk(Containment::ReadOnly, &events), Err(RecoveryError::MotionMismatch));
+    }
+
+    #[test]
+    fn out_of_order_events_are_illegal() {
+        let states = [
+            S::Normal,
+            S::Contained { level: Containment::Quarantine },
+            S::ThawRequested { level: Containment::Quarantine, motion: MOTION },
+            S::VotesConfirmed { level: Containment::Quarantine },
+            S::VetoReleased { level: Containment::Quarantine },
+            S::BackupRestored { level: Containment::RollbackToBackup },
+        ];
+        let events = [
+            E::RequestThaw { motion: MOTION },
+            E::VotePassed { motion: MOTION },
+            E::VetoReleased,
+            E::BackupRestored,
+            E::Resume { phi: 0.5 },
+        ];
+        // Única transição legal a partir de cada estado (índice do evento)
+        let legal = [None, Some(0), Some(1), Some(2), Some(4), Some(4)];
+        for (state, allowed) in states.iter().zip(legal) {
+            for (index, event) in events.iter().enumerate() {
+                let result = recovery_step(*state, *event);
+                assert_eq!(result.is_ok(), allowed == Some(index), "{state:?} + {event:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn trigger_escalates_and_restarts() {
+        let state = S::VotesConfirmed { level: Containment::ReadOnly };
+        assert_eq!(
+            recovery_step(state, E::Trigger(Containment::Throttle)),
+            Ok(S::Contained { level: Containment::ReadOnly })
+        );
+        assert_eq!(
+            recovery_step(state, E::Trigger(Containment::HardFreeze)),
+            Ok(S::Contained { level: Containment::HardFreeze })
+        );
+        let first = recovery_step(S::Normal, E::Trigger(Containment::Throttle));
+        assert_eq!(first, Ok(S::Contained { level: Containment::Throttle }));
+    }
+}
@@ -0,0 +1,810 @@
+//! Primitivas criptográficas `no_std`: SHA-256, SHA-512 e Ed25519 (RFC 8032)
+//! Implementação de tempo constante no caminho do escalar secreto, sem alocação e sem recursão.
+
+// ============================================================================
+// SHA-512 (FIPS 180-4)
+// ============================================================================
+
+const SHA512_K: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+const SHA512_IV: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+/// Hasher SHA-512 incremental (sem alocação)
+#[derive(Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffered: usize,
+    length: u128,
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha512 {
+    pub fn new() -> Self {
+        Self { state: SHA512_IV, buffer: [0; 128], buffered: 0, length: 0 }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len() as u128);
+        for &byte in data {
+            self.buffer[self.buffered] = byte;
+            self.buffered += 1;
+            if self.buffered == 128 {
+                let block = self.buffer;
+                self.compress(&block);
+                self.buffered = 0;
+            }
+        }
+    }
+
+    pub fn finalize(mut self) -> [u8; 64] {
+        let bit_len = self.length.wrapping_mul(8);
+        self.buffer[self.buffered] = 0x80;
+        self.buffered += 1;
+        if self.buffered > 112 {
+            for b in self.buffer[self.buffered..].iter_mut() { *b = 0; }
+            let block = self.buffer;
+            self.compress(&block);
+            self.buffered = 0;
+        }
+        for b in self.buffer[self.buffered..112].iter_mut() { *b = 0; }
+        self.buffer[112..].copy_from_slice(&bit_len.to_be_bytes());
+        let block = self.buffer;
+        self.compress(&block);
+
+        let mut out = [0u8; 64];
+        for (chunk, word) in out.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+
+    fn compress(&mut self, block: &[u8; 128]) {
+        let mut w = [0u64; 80];
+        for (i, chunk) in block.chunks_exact(8).enumerate() {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(chunk);
+            w[i] = u64::from_be_bytes(word);
+        }
+        for i in 16..80 {
+            let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for i in 0..80 {
+            let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA512_K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (s, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+/// SHA-512 de uma mensagem contígua
+pub fn sha512(data: &[u8]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+// ============================================================================
+// SHA-256 (FIPS 180-4)
+// ============================================================================
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Vetores de resposta conhecida (FIPS 180-2, apêndice B): mensagem, digest
+pub const SHA256_KAT: [(&[u8], [u8; 32]); 4] = [
+    (
+        b"",
+        [
+            0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f, 0xb9, 0x24,
+            0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b, 0x78, 0x52, 0xb8, 0x55,
+        ],
+    ),
+    (
+        b"abc",
+        [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23,
+            0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+        ],
+    ),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        [
+            0x24, 0x8d, 0x6a, 0x61, 0xd2, 0x06, 0x38, 0xb8, 0xe5, 0xc0, 0x26, 0x93, 0x0c, 0x3e, 0x60, 0x39,
+            0xa3, 0x3c, 0xe4, 0x59, 0x64, 0xff, 0x21, 0x67, 0xf6, 0xec, 0xed, 0xd4, 0x19, 0xdb, 0x06, 0xc1,
+        ],
+    ),
+    (
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        [
+            0xcf, 0x5b, 0x16, 0xa7, 0x78, 0xaf, 0x83, 0x80, 0x03, 0x6c, 0xe5, 0x9e, 0x7b, 0x04, 0x92, 0x37,
+            0x0b, 0x24, 0x9b, 0x11, 0xe8, 0xf0, 0x7a, 0x51, 0xaf, 0xac, 0x45, 0x03, 0x7a, 0xfe, 0xe9, 0xd1,
+        ],
+    ),
+];
+
+/// Hasher SHA-256 incremental (sem alocação)
+#[derive(Clone)]
+pub struct Sha256 {
+    state: [u32; 8],
+    buffer: [u8; 64],
+    buffered: usize,
+    length: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    pub fn new() -> Self {
+        Self { state: SHA256_IV, buffer: [0; 64], buffered: 0, length: 0 }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        for &byte in data {
+            self.buffer[self.buffered] = byte;
+            self.buffered += 1;
+            if self.buffered == 64 {
+                let block = self.buffer;
+                self.compress(&block);
+                self.buffered = 0;
+            }
+        }
+    }
+
+    pub fn finalize(mut self) -> [u8; 32] {
+        let bit_len = self.length.wrapping_mul(8);
+        self.buffer[self.buffered] = 0x80;
+        self.buffered += 1;
+        if self.buffered > 56 {
+            for b in self.buffer[self.buffered..].iter_mut() { *b = 0; }
+            let block = self.buffer;
+            self.compress(&block);
+            self.buffered = 0;
+        }
+        for b in self.buffer[self.buffered..56].iter_mut() { *b = 0; }
+        self.buffer[56..].copy_from_slice(&bit_len.to_be_bytes());
+        let block = self.buffer;
+        self.compress(&block);
+
+        let mut out = [0u8; 32];
+        for (chunk, word) in out.chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+
+    fn compress(&mut self, block: &[u8; 64]) {
+        let mut w = [0u32; 64];
+        for (i, chunk) in block.chunks_exact(4).enumerate() {
+            let mut word = [0u8; 4];
+            word.copy_from_slice(chunk);
+            w[i] = u32::from_be_bytes(word);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA256_K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (s, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+/// SHA-256 de uma mensagem contígua
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// Confere `SHA256_KAT`, inclusive com a mensagem entregue byte a byte
+pub fn sha256_self_test() -> bool {
+    SHA256_KAT.iter().all(|(message, expected)| {
+        let mut hasher = Sha256::new();
+        for byte in message.iter() {
+            hasher.update(core::slice::from_ref(byte));
+        }
+        sha256(message) == *expected && hasher.finalize() == *expected
+    })
+}
+
+/// Comparação de tempo constante
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+// ============================================================================
+// CORPO GF(2^255 - 19) — 16 limbs de 16 bits
+// ============================================================================
+
+type Gf = [i64; 16];
+
+const GF0: Gf = [0; 16];
+const GF1: Gf = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+const D: Gf = [
+    0x78a3, 0x1359, 0x4dca, 0x75eb, 0xd8ab, 0x4141, 0x0a4d, 0x0070,
+    0xe898, 0x7779, 0x4079, 0x8cc7, 0xfe73, 0x2b6f, 0x6cee, 0x5203,
+];
+const D2: Gf = [
+    0xf159, 0x26b2, 0x9b94, 0xebd6, 0xb156, 0x8283, 0x149a, 0x00e0,
+    0xd130, 0xeef3, 0x80f2, 0x198e, 0xfce7, 0x56df, 0xd9dc, 0x2406,
+];
+const BASE_X: Gf = [
+    0xd51a, 0x8f25, 0x2d60, 0xc956, 0xa7b2, 0x9525, 0xc760, 0x692c,
+    0xdc5c, 0xfdd6, 0xe231, 0xc0a4, 0x53fe, 0xcd6e, 0x36d3, 0x2169,
+];
+const BASE_Y: Gf = [
+    0x6658, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666,
+    0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666,
+];
+const SQRT_M1: Gf = [
+    0xa0b0, 0x4a0e, 0x1b27, 0xc4ee, 0xe478, 0xad2f, 0x1806, 0x2f43,
+    0xd7a7, 0x3dfb, 0x0099, 0x2b4d, 0xdf0b, 0x4fc1, 0x2480, 0x2b83,
+];
+
+/// Ordem do subgrupo primo: L = 2^252 + 27742317777372353535851937790883648493
+const L: [i64; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+];
+
+fn carry25519(o: &mut Gf) {
+    for i in 0..16 {
+        o[i] += 1 << 16;
+        let c = o[i] >> 16;
+        if i < 15 {
+            o[i + 1] += c - 1;
+        } else {
+            o[0] += 38 * (c - 1);
+        }
+        o[i] -= c << 16;
+    }
+}
+
+fn select25519(p: &mut Gf, q: &mut Gf, bit: i64) {
+    let mask = !(bit - 1);
+    for i in 0..16 {
+        let t = mask & (p[i] ^ q[i]);
+        p[i] ^= t;
+        q[i] ^= t;
+    }
+}
+
+fn pack25519(n: &Gf) -> [u8; 32] {
+    let mut t = *n;
+    carry25519(&mut t);
+    carry25519(&mut t);
+    carry25519(&mut t);
+    let mut m = GF0;
+    for _ in 0..2 {
+        m[0] = t[0] - 0xffed;
+        for i in 1..15 {
+            m[i] = t[i] - 0xffff - ((m[i - 1] >> 16) & 1);
+            m[i - 1] &= 0xffff;
+        }
+        m[15] = t[15] - 0x7fff - ((m[14] >> 16) & 1);
+        let borrow = (m[15] >> 16) & 1;
+        m[14] &= 0xffff;
+        select25519(&mut t, &mut m, 1 - borrow);
+    }
+    let mut out = [0u8; 32];
+    for i in 0..16 {
+        out[2 * i] = (t[i] & 0xff) as u8;
+        out[2 * i + 1] = ((t[i] >> 8) & 0xff) as u8;
+    }
+    out
+}
+
+fn unpack25519(n: &[u8; 32]) -> Gf {
+    let mut o = GF0;
+    for i in 0..16 {
+        o[i] = n[2 * i] as i64 + ((n[2 * i + 1] as i64) << 8);
+    }
+    o[15] &= 0x7fff;
+    o
+}
+
+fn neq25519(a: &Gf, b: &Gf) -> bool {
+    !ct_eq(&pack25519(a), &pack25519(b))
+}
+
+fn parity25519(a: &Gf) -> u8 {
+    pack25519(a)[0] & 1
+}
+
+fn gf_add(a: &Gf, b: &Gf) -> Gf {
+    let mut o = GF0;
+    for i in 0..16 { o[i] = a[i] + b[i]; }
+    o
+}
+
+fn gf_sub(a: &Gf, b: &Gf) -> Gf {
+    let mut o = GF0;
+    for i in 0..16 { o[i] = a[i] - b[i]; }
+    o
+}
+
+fn gf_mul(a: &Gf, b: &Gf) -> Gf {
+    let mut t = [0i64; 31];
+    for i in 0..16 {
+        for j in 0..16 {
+            t[i + j] += a[i] * b[j];
+        }
+    }
+    for i in 0..15 {
+        t[i] += 38 * t[i + 16];
+    }
+    let mut o = GF0;
+    o.copy_from_slice(&t[..16]);
+    carry25519(&mut o);
+    carry25519(&mut o);
+    o
+}
+
+fn gf_square(a: &Gf) -> Gf {
+    gf_mul(a, a)
+}
+
+fn gf_invert(i: &Gf) -> Gf {
+    let mut c = *i;
+    for a in (0..=253).rev() {
+        c = gf_square(&c);
+        if a != 2 && a != 4 {
+            c = gf_mul(&c, i);
+        }
+    }
+    c
+}
+
+fn gf_pow2523(i: &Gf) -> Gf {
+    let mut c = *i;
+    for a in (0..=250).rev() {
+        c = gf_square(&c);
+        if a != 1 {
+            c = gf_mul(&c, i);
+        }
+    }
+    c
+}
+
+// ============================================================================
+// GRUPO DE EDWARDS (coordenadas estendidas X, Y, Z, T)
+// ============================================================================
+
+type Point = [Gf; 4];
+
+fn point_add(p: &mut Point, q: &Point) {
+    let a = gf_mul(&gf_sub(&p[1], &p[0]), &gf_sub(&q[1], &q[0]));
+    let b = gf_mul(&gf_add(&p[0], &p[1]), &gf_add(&q[0], &q[1]));
+    let c = gf_mul(&gf_mul(&p[3], &q[3]), &D2);
+    let d0 = gf_mul(&p[2], &q[2]);
+    let d = gf_add(&d0, &d0);
+    let e = gf_sub(&b, &a);
+    let f = gf_sub(&d, &c);
+    let g = gf_add(&d, &c);
+    let h = gf_add(&b, &a);
+    p[0] = gf_mul(&e, &f);
+    p[1] = gf_mul(&h, &g);
+    p[2] = gf_mul(&g, &f);
+    p[3] = gf_mul(&e, &h);
+}
+
+fn point_cswap(p: &mut Point, q: &mut Point, bit: u8) {
+    for i in 0..4 {
+        select25519(&mut p[i], &mut q[i], bit as i64);
+    }
+}
+
+fn point_pack(p: &Point) -> [u8; 32] {
+    let zi = gf_invert(&p[2]);
+    let tx = gf_mul(&p[0], &zi);
+    let ty = gf_mul(&p[1], &zi);
+    let mut r = pack25519(&ty);
+    r[31] ^= parity25519(&tx) << 7;
+    r
+}
+
+/// Montgomery ladder de tempo constante sobre os 256 bits do escalar
+fn scalar_mult(q: &Point, s: &[u8; 32]) -> Point {
+    let mut p: Point = [GF0, GF1, GF1, GF0];
+    let mut q = *q;
+    for i in (0..256).rev() {
+        let bit = (s[i / 8] >> (i & 7)) & 1;
+        point_cswap(&mut p, &mut q, bit);
+        point_add(&mut q, &p);
+        let doubled = p;
+        point_add(&mut p, &doubled);
+        point_cswap(&mut p, &mut q, bit);
+    }
+    p
+}
+
+fn scalar_base(s: &[u8; 32]) -> Point {
+    let base: Point = [BASE_X, BASE_Y, GF1, gf_mul(&BASE_X, &BASE_Y)];
+    scalar_mult(&base, s)
+}
+
+/// Descompacta `-P` (negado), como exigido pela equação de verificação
+fn unpack_neg(p: &[u8; 32]) -> Option<Point> {
+    let z = GF1;
+    let y = unpack25519(p);
+    let y2 = gf_square(&y);
+    let den = gf_add(&z, &gf_mul(&y2, &D));
+    let num = gf_sub(&y2, &z);
+
+    let den2 = gf_square(&den);
+    let den4 = gf_square(&den2);
+    let den6 = gf_mul(&den4, &den2);
+    let mut t = gf_mul(&gf_mul(&den6, &num), &den);
+    t = gf_pow2523(&t);
+    t = gf_mul(&gf_mul(&gf_mul(&t, &num), &den), &den);
+    let mut x = gf_mul(&t, &den);
+
+    let mut chk = gf_mul(&gf_square(&x), &den);
+    if neq25519(&chk, &num) {
+        x = gf_mul(&x, &SQRT_M1);
+    }
+    chk = gf_mul(&gf_square(&x), &den);
+    if neq25519(&chk, &num) {
+        return None;
+    }
+    if parity25519(&x) == (p[31] >> 7) {
+        x = gf_sub(&GF0, &x);
+    }
+    let t = gf_mul(&x, &y);
+    Some([x, y, z, t])
+}
+
+// ============================================================================
+// ARITMÉTICA MÓDULO L
+// ============================================================================
+
+fn mod_l(x: &mut [i64; 64]) -> [u8; 32] {
+    for i in (32..64).rev() {
+        let mut carry = 0;
+        let mut j = i - 32;
+        while j < i - 12 {
+            x[j] += carry - 16 * x[i] * L[j - (i - 32)];
+            carry = (x[j] + 128) >> 8;
+            x[j] -= carry << 8;
+            j += 1;
+        }
+        x[j] += carry;
+        x[i] = 0;
+    }
+    let mut carry = 0;
+    for j in 0..32 {
+        x[j] += carry - (x[31] >> 4) * L[j];
+        carry = x[j] >> 8;
+        x[j] &= 255;
+    }
+    for j in 0..32 {
+        x[j] -= carry * L[j];
+    }
+    let mut r = [0u8; 32];
+    for i in 0..32 {
+        x[i + 1] += x[i] >> 8;
+        r[i] = (x[i] & 255) as u8;
+    }
+    r
+}
+
+fn reduce_wide(wide: &[u8; 64]) -> [u8; 32] {
+    let mut x = [0i64; 64];
+    for (xi, &b) in x.iter_mut().zip(wide.iter()) {
+        *xi = b as i64;
+    }
+    mod_l(&mut x)
+}
+
+/// Escalar canônico (estritamente menor que L), rejeitando assinaturas maleáveis
+fn scalar_is_canonical(s: &[u8; 32]) -> bool {
+    for i in (0..32).rev() {
+        let l = L[i] as u8;
+        if s[i] < l {
+            return true;
+        }
+        if s[i] > l {
+            return false;
+        }
+    }
+    false
+}
+
+// ============================================================================
+// ED25519
+// ============================================================================
+
+/// Chave de assinatura Ed25519 derivada de uma semente de 32 bytes
+#[derive(Clone)]
+pub struct SigningKey {
+    scalar: [u8; 32],
+    prefix: [u8; 32],
+    public: [u8; 32],
+}
+
+impl SigningKey {
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let h = sha512(seed);
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&h[..32]);
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&h[32..]);
+        let public = point_pack(&scalar_base(&scalar));
+        Self { scalar, prefix, public }
+    }
+
+    pub fn public_key(&self) -> [u8; 32] {
+        self.public
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let mut hasher = Sha512::new();
+        hasher.update(&self.prefix);
+        hasher.update(message);
+        let r = reduce_wide(&hasher.finalize());
+        let big_r = point_pack(&scalar_base(&r));
+
+        let mut hasher = Sha512::new();
+        hasher.update(&big_r);
+        hasher.update(&self.public);
+        hasher.update(message);
+        let h = reduce_wide(&hasher.finalize());
+
+        let mut x = [0i64; 64];
+        for i in 0..32 {
+            x[i] = r[i] as i64;
+        }
+        for i in 0..32 {
+            for j in 0..32 {
+                x[i + j] += h[i] as i64 * self.scalar[j] as i64;
+            }
+        }
+        let s = mod_l(&mut x);
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&big_r);
+        signature[32..].copy_from_slice(&s);
+        signature
+    }
+}
+
+/// Verificação Ed25519: `[S]B == R + [H(R‖A‖M)]A`
+pub fn verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&signature[32..]);
+    if !scalar_is_canonical(&s) {
+        return false;
+    }
+    let neg_a = match unpack_neg(public_key) {
+        Some(point) => point,
+        None => return false,
+    };
+
+    let mut hasher = Sha512::new();
+    hasher.update(&signature[..32]);
+    hasher.update(public_key);
+    hasher.update(message);
+    let h = reduce_wide(&hasher.finalize());
+
+    let mut p = scalar_mult(&neg_a, &h);
+    let sb = scalar_base(&s);
+    point_add(&mut p, &sb);
+    ct_eq(&point_pack(&p), &signature[..32])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex<const N: usize>(text: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        assert_eq!(text.len(), 2 * N);
+        for (byte, pair) in out.iter_mut().zip(text.as_bytes().chunks_exact(2)) {
+            let digit = |c: u8| (c as char).to_digit(16).unwrap() as u8;
+            *byte = digit(pair[0]) << 4 | digit(pair[1]);
+        }
+        out
+    }
+
+    /// FIPS 180-4 (exemplos do NIST): "", "abc", mensagem de 896 bits
+    const SHA512_KAT: [(&[u8], &str); 3] = [
+        (b"", "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"),
+        (b"abc", "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"),
+        (
+            b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909",
+        ),
+    ];
+
+    /// RFC 8032 §7.1: TEST 1, 2, 3 e SHA(abc) — (semente, chave pública, mensagem, assinatura)
+    const ED25519_KAT: [(&str, &str, &str, &str); 4] = [
+        (
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        ),
+        (
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ),
+        (
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        ),
+        (
+            "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+            "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+        ),
+    ];
+
+    fn message(text: &str) -> alloc::vec::Vec<u8> {
+        text.as_bytes().chunks_exact(2).map(|pair| hex::<1>(core::str::from_utf8(pair).unwrap())[0]).collect()
+    }
+
+    #[test]
+    fn sha512_known_answers() {
+        for (input, expected) in SHA512_KAT {
+            assert_eq!(sha512(input), hex::<64>(expected));
+            let mut hasher = Sha512::new();
+            for byte in input {
+                hasher.update(&[*byte]);
+            }
+            assert_eq!(hasher.finalize(), hex::<64>(expected));
+        }
+    }
+
+    #[test]
+    fn sha512_million_a() {
+        let mut hasher = Sha512::new();
+        for _ in 0..1000 {
+            hasher.update(&[b'a'; 1000]);
+        }
+        let expected = "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973ebde0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b";
+        assert_eq!(hasher.finalize(), hex::<64>(expected));
+    }
+
+    #[test]
+    fn sha256_known_answers() {
+        assert!(sha256_self_test());
+        for (input, expected) in SHA256_KAT {
+            assert_eq!(sha256(input), expected);
+        }
+    }
+
+    #[test]
+    fn ed25519_rfc8032_vectors() {
+        for (seed, public, text, signature) in ED25519_KAT {
+            let key = SigningKey::from_seed(&hex::<32>(seed));
+            let msg = message(text);
+            let expected = hex::<64>(signature);
+            assert_eq!(key.public_key(), hex::<32>(public));
+            assert_eq!(key.sign(&msg), expected);
+            assert!(verify(&hex::<32>(public), &msg, &expected));
+        }
+    }
+
+    #[test]
+    fn ed25519_rejects_tampering() {
+        let (seed, public, text, signature) = ED25519_KAT[2];
+        let public = hex::<32>(public);
+        let msg = message(text);
+        let signature = hex::<64>(signature);
+        assert!(verify(&public, &msg, &signature));
+
+        let mut forged = signature;
+        forged[0] ^= 1;
+        assert!(!verify(&public, &msg, &forged));
+        let mut altered = msg.clone();
+        altered[0] ^= 1;
+        assert!(!verify(&public, &altered, &signature));
+        let other = SigningKey::from_seed(&hex::<32>(seed).map(|b| b ^ 0xff)).public_key();
+        assert!(!verify(&other, &msg, &signature));
+        // S fora do intervalo [0, L) não é aceito (maleabilidade)
+        let mut malleable = signature;
+        malleable[63] |= 0xf0;
+        assert!(!verify(&public, &msg, &malleable));
+    }
+}
@@ -0,0 +1,1148 @@
+//! Motor Datalog limitado para os predicados de governança
+//! Porta nativa de `sasc/prolog/governance.pl`: as cláusulas dos 7 Gates,
+//! `ethical_transition` e `autopoiesis_allowed` lidas do próprio arquivo e avaliadas de baixo para cima,
+//! sem recursão, com tabelas de símbolos, fatos e passos de junção limitados.
+//!
+//! Sintaxe aceita (subconjunto de Prolog): fatos `p(a, 1.0).`, regras
+//! `h(X) :- p(X, Y), Y >= 0.99.`, comparações `< =< > >= == =:= =\= \==` entre
+//! expressões `T`, `T + T`, `T - T` e `abs(T - T)`. Toda variável da cabeça deve
+//! aparecer num átomo do corpo, e toda variável de comparação num átomo anterior.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::backup::ReplicaBackend;
+use crate::config::GateConfig;
+use crate::gates::{ContainmentError, Gate, SevenFoldSeal, VetoStatus};
+use crate::veto::VetoScope;
+
+/// Limites de alocação do motor (Power of 10, regra 2)
+pub const MAX_SYMBOLS: usize = 256;
+pub const MAX_FACTS: usize = 4096;
+pub const MAX_RULES: usize = 64;
+pub const MAX_ARITY: usize = 4;
+pub const MAX_BODY: usize = 16;
+pub const MAX_VARS: usize = 16;
+pub const MAX_ITERATIONS: usize = 64;
+pub const MAX_JOIN_STEPS: usize = 1_000_000;
+
+/// Texto de `sasc/prolog/governance.pl`, fonte única dos gates e das regras derivadas
+pub const GOVERNANCE_PL: &str = include_str!("../../prolog/governance.pl");
+
+/// Predicados de `governance.pl` avaliáveis pelo motor, além de `gate/3`;
+/// os demais dependem de `forall`/`not` e ficam de fora
+const GOVERNANCE_HEADS: [&str; 3] = ["all_gates_pass", "ethical_transition", "autopoiesis_allowed"];
+
+/// Liga cada gate à métrica que ele julga (mesmos pares de `all_gates_pass`)
+const GATE_BRIDGE: &str = "
+gate_metric(1, spin). gate_metric(2, volume). gate_metric(3, entropy). gate_metric(4, firewall).
+gate_metric(5, backup). gate_metric(6, consensus). gate_metric(7, veto).
+gate_pass(E, N) :- gate_metric(N, M), entity_metric(E, M, X), gate(N, _, X).
+";
+
+/// Cláusulas de um texto Prolog, sem comentários, com a linha em que começam.
+/// Uma cláusula termina no `.` que fecha a linha.
+fn prolog_clauses(source: &str) -> Vec<(usize, String)> {
+    let mut clauses = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    for (index, line) in source.lines().enumerate() {
+        let code = line.split('%').next().unwrap_or("").trim();
+        if code.is_empty() {
+            continue;
+        }
+        if current.is_empty() {
+            start = index + 1;
+        } else {
+            current.push(' ');
+        }
+        current.push_str(code);
+        if code.ends_with('.') {
+            clauses.push((start, core::mem::take(&mut current)));
+        }
+    }
+    clauses
+}
+
+/// Nome do predicado da cabeça (vazio para diretivas `:- ...`)
+fn clause_head(clause: &str) -> &str {
+    let end = clause.find(['(', ':', '.']).unwrap_or(clause.len());
+    clause[..end].trim()
+}
+
+/// `gate(N, Nome, X) :- Corpo.` vira `gate(N, Nome, X) :- entity_metric(_, _, X), Corpo.`:
+/// X passa a percorrer só os valores medidos, o que torna a regra segura
+fn restrict_gate(clause: &str) -> Option<String> {
+    let (head, body) = clause.split_once(":-")?;
+    let args = head.trim().strip_prefix("gate(")?.strip_suffix(')')?;
+    let var = args.rsplit(',').next()?.trim();
+    if !var.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return None;
+    }
+    Some(format!("{} :- entity_metric(_, _, {var}), {}", head.trim(), body.trim()))
+}
+
+/// Programa Datalog com as cláusulas de governança de um texto Prolog no formato de
+/// `governance.pl`: `gate/3` com restrição de alcance, `gate_pass(E, N)` e as regras
+/// derivadas. Um `gate/3` que não seja regra sobre variável resulta em `UnsafeRule`.
+pub fn governance_program(source: &str) -> Result<String, DatalogError> {
+    let mut program = String::from(GATE_BRIDGE);
+    for (line, clause) in prolog_clauses(source) {
+        let head = clause_head(&clause);
+        if head == "gate" {
+            program.push_str(&restrict_gate(&clause).ok_or(DatalogError::UnsafeRule { line })?);
+        } else if GOVERNANCE_HEADS.contains(&head) {
+            program.push_str(&clause);
+        } else {
+            continue;
+        }
+        program.push('\n');
+    }
+    Ok(program)
+}
+
+/// `governance.pl` com os gates de `SevenFoldSeal::evaluate` sob os limiares de
+/// `config` no lugar dos gates do arquivo. O arquivo diverge do selo em três pontos,
+/// relatados por `crosscheck` em `Conformance::drift`: alvo de entropia 0.693147 com
+/// tolerância estrita, expansão de firewall negativa reprovada e gate 6 `Votes == 1.0`.
+pub fn governance_rules(config: &GateConfig) -> String {
+    let mut rules = format!(
+        "gate(1, spin_total, Spin) :- Spin >= {:?}, Spin =< {:?}.
+gate(2, volume_coherence, Vol) :- Vol > {:?}.
+gate(3, entropy_exact, Ent) :- abs(Ent - {:?}) =< {:?}.
+gate(4, firewall_safe, Exp) :- Exp =< {:?}.
+gate(5, backup_triplicate, Status) :- Status == verified.
+gate(6, cardinal_consensus, Votes) :- Votes >= {:?}.
+gate(7, prince_veto, Status) :- Status == released.
+",
+        config.spin_min,
+        config.spin_max,
+        config.compton_volume,
+        config.target_entropy,
+        config.entropy_tolerance,
+        config.firewall_max,
+        config.consensus_required,
+    );
+    for (_, clause) in prolog_clauses(GOVERNANCE_PL) {
+        if clause_head(&clause) != "gate" {
+            rules.push_str(&clause);
+            rules.push('\n');
+        }
+    }
+    rules
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatalogError {
+    /// Texto inválido na posição indicada (a partir de 1)
+    Syntax { line: usize, column: usize },
+    /// Variável não ligada por um átomo do corpo
+    UnsafeRule { line: usize },
+    /// Fato com variáveis
+    NonGroundFact { line: usize },
+    ArityMismatch,
+    TooManySymbols,
+    TooManyFacts,
+    TooManyRules,
+    TooManyVariables,
+    BodyTooLong,
+    /// Ponto fixo não atingido dentro de `MAX_ITERATIONS` ou `MAX_JOIN_STEPS`
+    Budget,
+    /// Métricas NaN/infinitas não são representáveis como fatos
+    NonFinite,
+}
+
+/// Constante do universo de Herbrand: símbolo internado ou número finito
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Sym(u16),
+    Num(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Term {
+    Var(u8),
+    Const(Value),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Atom {
+    pred: u16,
+    arity: u8,
+    args: [Term; MAX_ARITY],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Arith {
+    Add,
+    Sub,
+}
+
+/// `T`, `T ± T` ou `abs(T ± T)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Expr {
+    abs: bool,
+    lhs: Term,
+    rhs: Option<(Arith, Term)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Literal {
+    Atom(Atom),
+    Compare { left: Expr, op: Cmp, right: Expr },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    head: Atom,
+    body: Vec<Literal>,
+    vars: u8,
+}
+
+/// Fato derivado ou declarado
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fact {
+    pub pred: u16,
+    pub arity: u8,
+    pub args: [Value; MAX_ARITY],
+}
+
+impl Fact {
+    pub fn args(&self) -> &[Value] {
+        &self.args[..self.arity as usize]
+    }
+}
+
+type Bindings = [Option<Value>; MAX_VARS];
+
+/// Cláusula analisada; corpo vazio = fato
+struct Clause {
+    head: Atom,
+    body: Vec<Literal>,
+    vars: u8,
+    line: usize,
+}
+
+pub struct Datalog {
+    symbols: Vec<String>,
+    /// Aridade registrada por predicado (0 = ainda não usado)
+    arities: Vec<u8>,
+    rules: Vec<Rule>,
+    facts: Vec<Fact>,
+    /// Novos fatos ou regras desde o último ponto fixo
+    dirty: bool,
+}
+
+impl Default for Datalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Datalog {
+    pub fn new() -> Self {
+        Self { symbols: Vec::new(), arities: Vec::new(), rules: Vec::new(), facts: Vec::new(), dirty: false }
+    }
+
+    /// Motor carregado com as regras de `GOVERNANCE_PL`
+    pub fn governance() -> Result<Self, DatalogError> {
+        let mut engine = Self::new();
+        engine.load(&governance_program(GOVERNANCE_PL)?)?;
+        Ok(engine)
+    }
+
+    /// Motor carregado com os gates do selo sob os limiares de `config` (`governance_rules`)
+    pub fn governance_with(config: &GateConfig) -> Result<Self, DatalogError> {
+        let mut engine = Self::new();
+        engine.load(&governance_program(&governance_rules(config))?)?;
+        Ok(engine)
+    }
+
+    pub fn intern(&mut self, name: &str) -> Result<Value, DatalogError> {
+        self.symbol_id(name).map(Value::Sym)
+    }
+
+    pub fn symbol(&self, value: Value) -> Option<&str> {
+        match value {
+            Value::Sym(id) => self.symbols.get(id as usize).map(String::as_str),
+            Value::Num(_) => None,
+        }
+    }
+
+    pub fn facts(&self) -> &[Fact] {
+        &self.facts
+    }
+
+    fn symbol_id(&mut self, name: &str) -> Result<u16, DatalogError> {
+        if let Some(id) = self.symbols.iter().position(|s| s == name) {
+            return Ok(id as u16);
+        }
+        if self.symbols.len() >= MAX_SYMBOLS {
+            return Err(DatalogError::TooManySymbols);
+        }
+        self.symbols.push(String::from(name));
+        self.arities.push(0);
+        Ok((self.symbols.len() - 1) as u16)
+    }
+
+    fn check_arity(&mut self, pred: u16, arity: u8) -> Result<(), DatalogError> {
+        let slot = &mut self.arities[pred as usize];
+        if *slot == 0 {
+            *slot = arity + 1;
+        } else if *slot != arity + 1 {
+            return Err(DatalogError::ArityMismatch);
+        }
+        Ok(())
+    }
+
+    /// Declara um fato `pred(args...)`
+    pub fn add_fact(&mut self, pred: &str, args: &[Value]) -> Result<(), DatalogError> {
+        if args.len() > MAX_ARITY {
+            return Err(DatalogError::ArityMismatch);
+        }
+        if args.iter().any(|v| matches!(v, Value::Num(n) if !n.is_finite())) {
+            return Err(DatalogError::NonFinite);
+        }
+        let pred = self.symbol_id(pred)?;
+        self.check_arity(pred, args.len() as u8)?;
+        let mut fact = Fact { pred, arity: args.len() as u8, args: [Value::Num(0.0); MAX_ARITY] };
+        fact.args[..args.len()].copy_from_slice(args);
+        if self.insert(fact)? {
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Atalho para `pred(a, b, ...)` com argumentos simbólicos
+    pub fn add_symbolic_fact(&mut self, pred: &str, args: &[&str]) -> Result<(), DatalogError> {
+        let mut values = [Value::Num(0.0); MAX_ARITY];
+        if args.len() > MAX_ARITY {
+            return Err(DatalogError::ArityMismatch);
+        }
+        for (slot, name) in values.iter_mut().zip(args) {
+            *slot = self.intern(name)?;
+        }
+        self.add_fact(pred, &values[..args.len()])
+    }
+
+    fn insert(&mut self, fact: Fact) -> Result<bool, DatalogError> {
+        if self.facts.contains(&fact) {
+            return Ok(false);
+        }
+        if self.facts.len() >= MAX_FACTS {
+            return Err(DatalogError::TooManyFacts);
+        }
+        self.facts.push(fact);
+        Ok(true)
+    }
+
+    /// Carrega fatos e regras em texto
+    pub fn load(&mut self, source: &str) -> Result<(), DatalogError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0, end: position_after(source) };
+        while parser.peek().is_some() {
+            let Clause { head, body, vars, line } = parser.clause(self)?;
+            if body.is_empty() {
+                let mut fact = Fact { pred: head.pred, arity: head.arity, args: [Value::Num(0.0); MAX_ARITY] };
+                for (slot, term) in fact.args.iter_mut().zip(&head.args[..head.arity as usize]) {
+                    match term {
+                        Term::Const(value) => *slot = *value,
+                        Term::Var(_) => return Err(DatalogError::NonGroundFact { line }),
+                    }
+                }
+                if self.insert(fact)? {
+                    self.dirty = true;
+                }
+                continue;
+            }
+            check_range_restricted(&head, &body).map_err(|_| DatalogError::UnsafeRule { line })?;
+            if self.rules.len() >= MAX_RULES {
+                return Err(DatalogError::TooManyRules);
+            }
+            self.rules.push(Rule { head, body, vars });
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// Avaliação ingênua até o ponto fixo, com orçamento de iterações e passos
+    pub fn evaluate(&mut self) -> Result<(), DatalogError> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let mut steps = 0usize;
+        for _ in 0..MAX_ITERATIONS {
+            let mut derived = Vec::new();
+            for rule in &self.rules {
+                fire(rule, &self.facts, &mut derived, &mut steps)?;
+            }
+            let mut changed = false;
+            for fact in derived {
+                changed |= self.insert(fact)?;
+            }
+            if !changed {
+                self.dirty = false;
+                return Ok(());
+            }
+        }
+        Err(DatalogError::Budget)
+    }
+
+    /// Responde uma consulta `pred(args)`; devolve as tuplas que a satisfazem
+    pub fn query(&mut self, goal: &str) -> Result<Vec<Fact>, DatalogError> {
+        let tokens = tokenize(goal)?;
+        let mut parser = Parser { tokens, pos: 0, end: position_after(goal) };
+        let mut vars = VarTable::default();
+        let atom = parser.atom(self, &mut vars)?;
+        if let Some(token) = parser.peek() {
+            if token.kind != Tok::Dot || parser.tokens.len() > parser.pos + 1 {
+                return Err(parser.error_at(token));
+            }
+        }
+        self.evaluate()?;
+
+        let mut out = Vec::new();
+        for fact in &self.facts {
+            let mut bindings: Bindings = [None; MAX_VARS];
+            if unify(&atom, fact, &mut bindings) {
+                out.push(*fact);
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn holds(&mut self, goal: &str) -> Result<bool, DatalogError> {
+        Ok(!self.query(goal)?.is_empty())
+    }
+}
+
+// ============================================================================
+// JUNÇÃO ITERATIVA (pilha explícita em vez de recursão)
+// ============================================================================
+
+fn fire(rule: &Rule, facts: &[Fact], out: &mut Vec<Fact>, steps: &mut usize) -> Result<(), DatalogError> {
+    let len = rule.body.len();
+    let mut cursor = [0usize; MAX_BODY];
+    let mut stack: [Bindings; MAX_BODY + 1] = [[None; MAX_VARS]; MAX_BODY + 1];
+    let mut depth = 0usize;
+
+    loop {
+        *steps += 1;
+        if *steps > MAX_JOIN_STEPS {
+            return Err(DatalogError::Budget);
+        }
+        if depth == len {
+            if let Some(fact) = instantiate(&rule.head, &stack[depth]) {
+                if !facts.contains(&fact) && !out.contains(&fact) {
+                    if facts.len() + out.len() >= MAX_FACTS {
+                        return Err(DatalogError::TooManyFacts);
+                    }
+                    out.push(fact);
+                }
+            }
+            // Volta ao último literal para buscar a próxima solução
+            depth -= 1;
+            continue;
+        }
+
+        let advanced = match &rule.body[depth] {
+            Literal::Compare { left, op, right } => {
+                let first = cursor[depth] == 0;
+                cursor[depth] = 1;
+                if first && compare(left, *op, right, &stack[depth]) {
+                    stack[depth + 1] = stack[depth];
+                    true
+                } else {
+                    false
+                }
+            }
+            Literal::Atom(atom) => {
+                let mut found = false;
+                while cursor[depth] < facts.len() {
+                    let index = cursor[depth];
+                    cursor[depth] += 1;
+                    let mut bindings = stack[depth];
+                    if unify(atom, &facts[index], &mut bindings) {
+                        stack[depth + 1] = bindings;
+                        found = true;
+                        break;
+                    }
+                }
+                found
+            }
+        };
+
+        if advanced {
+            depth += 1;
+            if depth < len {
+                cursor[depth] = 0;
+            }
+        } else {
+            cursor[depth] = 0;
+            if depth == 0 {
+                return Ok(());
+            }
+            depth -= 1;
+        }
+    }
+}
+
+fn unify(atom: &Atom, fact: &Fact, bindings: &mut Bindings) -> bool {
+    if atom.pred != fact.pred || atom.arity != fact.arity {
+        return false;
+    }
+    for (term, value) in atom.args.iter().zip(fact.args()) {
+        match *term {
+            Term::Const(c) => {
+                if c != *value {
+                    return false;
+                }
+            }
+            Term::Var(v) => match bindings[v as usize] {
+                Some(bound) if bound != *value => return false,
+                Some(_) => {}
+                None => bindings[v as usize] = Some(*value),
+            },
+        }
+    }
+    true
+}
+
+fn instantiate(head: &Atom, bindings: &Bindings) -> Option<Fact> {
+    let mut fact = Fact { pred: head.pred, arity: head.arity, args: [Value::Num(0.0); MAX_ARITY] };
+    for (slot, term) in fact.args.iter_mut().zip(&head.args[..head.arity as usize]) {
+        *slot = resolve(*term, bindings)?;
+    }
+    Some(fact)
+}
+
+fn resolve(term: Term, bindings: &Bindings) -> Option<Value> {
+    match term {
+        Term::Const(value) => Some(value),
+        Term::Var(v) => bindings[v as usize],
+    }
+}
+
+fn eval(expr: &Expr, bindings: &Bindings) -> Option<Value> {
+    let lhs = resolve(expr.lhs, bindings)?;
+    let value = match (lhs, expr.rhs) {
+        (_, None) => lhs,
+        (Value::Num(a), Some((op, rhs))) => match (op, resolve(rhs, bindings)?) {
+            (Arith::Add, Value::Num(b)) => Value::Num(a + b),
+            (Arith::Sub, Value::Num(b)) => Value::Num(a - b),
+            _ => return None,
+        },
+        (Value::Sym(_), Some(_)) => return None,
+    };
+    match value {
+        Value::Num(n) if expr.abs => Some(Value::Num(if n < 0.0 { -n } else { n })),
+        Value::Sym(_) if expr.abs => None,
+        _ => Some(value),
+    }
+}
+
+fn compare(left: &Expr, op: Cmp, right: &Expr, bindings: &Bindings) -> bool {
+    let (Some(a), Some(b)) = (eval(left, bindings), eval(right, bindings)) else {
+        return false;
+    };
+    match (a, b, op) {
+        (Value::Num(x), Value::Num(y), _) => match op {
+            Cmp::Lt => x < y,
+            Cmp::Le => x <= y,
+            Cmp::Gt => x > y,
+            Cmp::Ge => x >= y,
+            Cmp::Eq => x == y,
+            Cmp::Ne => x != y,
+        },
+        (_, _, Cmp::Eq) => a == b,
+        (_, _, Cmp::Ne) => a != b,
+        _ => false,
+    }
+}
+
+/// Variáveis da cabeça e das comparações precisam ser ligadas por átomos anteriores
+fn check_range_restricted(head: &Atom, body: &[Literal]) -> Result<(), ()> {
+    let mut bound = [false; MAX_VARS];
+    let is_bound = |term: &Term, bound: &[bool; MAX_VARS]| match term {
+        Term::Var(v) => bound[*v as usize],
+        Term::Const(_) => true,
+    };
+    for literal in body {
+        match literal {
+            Literal::Atom(atom) => {
+                for term in &atom.args[..atom.arity as usize] {
+                    if let Term::Var(v) = term {
+                        bound[*v as usize] = true;
+                    }
+                }
+            }
+            Literal::Compare { left, right, .. } => {
+                for expr in [left, right] {
+                    let rhs_bound = expr.rhs.is_none_or(|(_, t)| is_bound(&t, &bound));
+                    if !is_bound(&expr.lhs, &bound) || !rhs_bound {
+                        return Err(());
+                    }
+                }
+            }
+        }
+    }
+    if head.args[..head.arity as usize].iter().all(|t| is_bound(t, &bound)) {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+// ============================================================================
+// ANÁLISE LÉXICA E SINTÁTICA
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tok {
+    /// Átomo/símbolo (inicial minúscula)
+    Name,
+    /// Variável (inicial maiúscula ou `_`)
+    Var,
+    Num(f64),
+    LParen,
+    RParen,
+    Comma,
+    Dot,
+    Neck,
+    Cmp(Cmp),
+    Plus,
+    Minus,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Tok,
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+fn position_after(source: &str) -> (usize, usize) {
+    let line = source.lines().count().max(1);
+    let column = source.lines().last().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, DatalogError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut line = 1;
+    let mut line_start = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        let column = source[line_start..i].chars().count() + 1;
+        let syntax = DatalogError::Syntax { line, column };
+        if b == b'\n' {
+            i += 1;
+            line += 1;
+            line_start = i;
+            continue;
+        }
+        if b == b'%' {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if b.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let kind = if b.is_ascii_alphabetic() || b == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            if b.is_ascii_lowercase() { Tok::Name } else { Tok::Var }
+        } else if b.is_ascii_digit() {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i + 1 < bytes.len() && bytes[i] == b'.' && bytes[i + 1].is_ascii_digit() {
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                let mut j = i + 1;
+                if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j].is_ascii_digit() {
+                    i = j;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            match source[start..i].parse::<f64>() {
+                Ok(n) if n.is_finite() => Tok::Num(n),
+                _ => return Err(syntax),
+            }
+        } else {
+            let rest = &source[i..];
+            let (kind, len) = if rest.starts_with(":-") {
+                (Tok::Neck, 2)
+            } else if rest.starts_with("=:=") {
+                (Tok::Cmp(Cmp::Eq), 3)
+            } else if rest.starts_with("=\\=") || rest.starts_with("\\==") {
+                (Tok::Cmp(Cmp::Ne), 3)
+            } else if rest.starts_with("==") {
+                (Tok::Cmp(Cmp::Eq), 2)
+            } else if rest.starts_with("=<") {
+                (Tok::Cmp(Cmp::Le), 2)
+            } else if rest.starts_with(">=") {
+                (Tok::Cmp(Cmp::Ge), 2)
+            } else {
+                match b {
+                    b'<' => (Tok::Cmp(Cmp::Lt), 1),
+                    b'>' => (Tok::Cmp(Cmp::Gt), 1),
+                    b'(' => (Tok::LParen, 1),
+                    b')' => (Tok::RParen, 1),
+                    b',' => (Tok::Comma, 1),
+                    b'.' => (Tok::Dot, 1),
+                    b'+' => (Tok::Plus, 1),
+                    b'-' => (Tok::Minus, 1),
+                    _ => return Err(syntax),
+                }
+            };
+            i += len;
+            kind
+        };
+        tokens.push(Token { kind, text: &source[start..i], line, column });
+    }
+    Ok(tokens)
+}
+
+/// Variáveis nomeadas de uma cláusula; `_` é sempre nova
+#[derive(Default)]
+struct VarTable<'a> {
+    names: Vec<&'a str>,
+}
+
+impl<'a> VarTable<'a> {
+    fn get(&mut self, name: &'a str) -> Result<u8, DatalogError> {
+        if name != "_" {
+            if let Some(i) = self.names.iter().position(|n| *n == name) {
+                return Ok(i as u8);
+            }
+        }
+        if self.names.len() >= MAX_VARS {
+            return Err(DatalogError::TooManyVariables);
+        }
+        self.names.push(name);
+        Ok((self.names.len() - 1) as u8)
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    end: (usize, usize),
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn peek_kind(&self, offset: usize) -> Option<Tok> {
+        self.tokens.get(self.pos + offset).map(|t| t.kind)
+    }
+
+    fn error_at(&self, token: Token) -> DatalogError {
+        DatalogError::Syntax { line: token.line, column: token.column }
+    }
+
+    fn next(&mut self) -> Result<Token<'a>, DatalogError> {
+        let token = self.peek().ok_or(DatalogError::Syntax { line: self.end.0, column: self.end.1 })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, kind: Tok) -> Result<Token<'a>, DatalogError> {
+        let token = self.next()?;
+        if token.kind != kind {
+            return Err(self.error_at(token));
+        }
+        Ok(token)
+    }
+
+    fn clause(&mut self, db: &mut Datalog) -> Result<Clause, DatalogError> {
+        let mut vars = VarTable::default();
+        let line = self.peek().map_or(self.end.0, |t| t.line);
+        let head = self.atom(db, &mut vars)?;
+        let mut body = Vec::new();
+
+        let token = self.next()?;
+        match token.kind {
+            Tok::Dot => {}
+            Tok::Neck => loop {
+                if body.len() >= MAX_BODY {
+                    return Err(DatalogError::BodyTooLong);
+                }
+                body.push(self.literal(db, &mut vars)?);
+                let sep = self.next()?;
+                match sep.kind {
+                    Tok::Comma => {}
+                    Tok::Dot => break,
+                    _ => return Err(self.error_at(sep)),
+                }
+            },
+            _ => return Err(self.error_at(token)),
+        }
+        Ok(Clause { head, body, vars: vars.names.len() as u8, line })
+    }
+
+    fn literal(&mut self, db: &mut Datalog, vars: &mut VarTable<'a>) -> Result<Literal, DatalogError> {
+        if let Some(token) = self.peek() {
+            let is_atom = token.kind == Tok::Name
+                && token.text != "abs"
+                && matches!(self.peek_kind(1), Some(Tok::LParen) | Some(Tok::Comma) | Some(Tok::Dot));
+            if is_atom {
+                return Ok(Literal::Atom(self.atom(db, vars)?));
+            }
+        }
+        let left = self.expr(db, vars)?;
+        let token = self.next()?;
+        let Tok::Cmp(op) = token.kind else {
+            return Err(self.error_at(token));
+        };
+        let right = self.expr(db, vars)?;
+        Ok(Literal::Compare { left, op, right })
+    }
+
+    fn atom(&mut self, db: &mut Datalog, vars: &mut VarTable<'a>) -> Result<Atom, DatalogError> {
+        let name = self.expect(Tok::Name)?;
+        let pred = db.symbol_id(name.text)?;
+        let mut atom = Atom { pred, arity: 0, args: [Term::Const(Value::Num(0.0)); MAX_ARITY] };
+        if self.peek_kind(0) == Some(Tok::LParen) {
+            self.pos += 1;
+            loop {
+                if atom.arity as usize >= MAX_ARITY {
+                    return Err(DatalogError::ArityMismatch);
+                }
+                atom.args[atom.arity as usize] = self.term(db, vars)?;
+                atom.arity += 1;
+                let sep = self.next()?;
+                match sep.kind {
+                    Tok::Comma => {}
+                    Tok::RParen => break,
+                    _ => return Err(self.error_at(sep)),
+                }
+            }
+        }
+        db.check_arity(pred, atom.arity)?;
+        Ok(atom)
+    }
+
+    fn term(&mut self, db: &mut Datalog, vars: &mut VarTable<'a>) -> Result<Term, DatalogError> {
+        let token = self.next()?;
+        match token.kind {
+            Tok::Var => Ok(Term::Var(vars.get(token.text)?)),
+            Tok::Num(n) => Ok(Term::Const(Value::Num(n))),
+            Tok::Name => Ok(Term::Const(Value::Sym(db.symbol_id(token.text)?))),
+            Tok::Minus => match self.next()? {
+                Token { kind: Tok::Num(n), .. } => Ok(Term::Const(Value::Num(-n))),
+                other => Err(self.error_at(other)),
+            },
+            _ => Err(self.error_at(token)),
+        }
+    }
+
+    fn expr(&mut self, db: &mut Datalog, vars: &mut VarTable<'a>) -> Result<Expr, DatalogError> {
+        let abs = self.peek().is_some_and(|t| t.kind == Tok::Name && t.text == "abs")
+            && self.peek_kind(1) == Some(Tok::LParen);
+        if abs {
+            self.pos += 2;
+        }
+        let lhs = self.term(db, vars)?;
+        let rhs = match self.peek_kind(0) {
+            Some(Tok::Plus) => {
+                self.pos += 1;
+                Some((Arith::Add, self.term(db, vars)?))
+            }
+            Some(Tok::Minus) => {
+                self.pos += 1;
+                Some((Arith::Sub, self.term(db, vars)?))
+            }
+            _ => None,
+        };
+        if abs {
+            self.expect(Tok::RParen)?;
+        }
+        Ok(Expr { abs, lhs, rhs })
+    }
+}
+
+// ============================================================================
+// CONFORMIDADE COM O SELO IMPERATIVO
+// ============================================================================
+
+/// Resultado da verificação cruzada entre o motor lógico e `SevenFoldSeal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conformance {
+    /// `gate_pass(seal, N)` derivado para cada gate
+    pub gates: [bool; 7],
+    pub all_gates_pass: bool,
+    /// Primeiro gate reprovado por `check_transition` (None = transição autorizada)
+    pub seal_failed_gate: Option<u8>,
+    pub agrees: bool,
+    /// `gate_pass(seal, N)` pelas cláusulas do próprio `governance.pl`
+    pub governance_pl: [bool; 7],
+    /// Gates em que `governance.pl` decide diferente do selo: limiares do arquivo
+    /// que o selo não adota
+    pub drift: [bool; 7],
+}
+
+fn failed_gate(error: &ContainmentError) -> u8 {
+    match error {
+        // Configuração inválida reprova antes de qualquer gate; o esgotamento do
+        // atestador não vem de `check_transition`
+        ContainmentError::InvalidConfig(_) | ContainmentError::AttestationExhausted => 0,
+        ContainmentError::Decoherence(_) => 1,
+        ContainmentError::InsufficientVolume => 2,
+        ContainmentError::EntanglementMismatch => 3,
+        ContainmentError::BreachRisk => 4,
+        ContainmentError::BackupIntegrity => 5,
+        ContainmentError::DemocraticViolation => 6,
+        ContainmentError::SovereignVetoActive => 7,
+    }
+}
+
+/// Carrega as métricas do selo como fatos `entity_metric(seal, ...)` e confere,
+/// gate a gate, que as regras portadas concordam com `check_transition`:
+/// o selo reprova exatamente no primeiro gate sem `gate_pass`. As mesmas métricas
+/// são julgadas pelas cláusulas de `governance.pl`, e cada divergência com o selo
+/// fica em `drift`. Métricas não finitas não viram fatos e resultam em
+/// `DatalogError::NonFinite`.
+pub fn crosscheck<B: ReplicaBackend>(seal: &SevenFoldSeal<B>, now: u64) -> Result<Conformance, DatalogError> {
+    let mut db = Datalog::governance_with(&seal.config)?;
+    let (gates, all_gates_pass) = judge_seal(&mut db, seal, now)?;
+    let mut file = Datalog::governance()?;
+    let (governance_pl, _) = judge_seal(&mut file, seal, now)?;
+
+    let seal_failed_gate = seal.check_transition(now).err().map(|e| failed_gate(&e));
+    let logic_failed_gate = gates.iter().position(|pass| !pass).map(|i| i as u8 + 1);
+    let mut drift = [false; 7];
+    for (slot, gate) in drift.iter_mut().zip(Gate::ALL) {
+        *slot = governance_pl[gate.number() as usize - 1] != seal.evaluate(gate, now).passed;
+    }
+    Ok(Conformance {
+        gates,
+        all_gates_pass,
+        seal_failed_gate,
+        agrees: seal_failed_gate == logic_failed_gate && all_gates_pass == seal_failed_gate.is_none(),
+        governance_pl,
+        drift,
+    })
+}
+
+/// `gate_pass(seal, N)` para cada gate e `all_gates_pass(seal)` sob as regras de `db`
+fn judge_seal<B: ReplicaBackend>(
+    db: &mut Datalog,
+    seal: &SevenFoldSeal<B>,
+    now: u64,
+) -> Result<([bool; 7], bool), DatalogError> {
+    let entity = db.intern("seal")?;
+    let metrics = [
+        ("spin", Value::Num(seal.metrics.total_spin)),
+        ("volume", Value::Num(seal.metrics.coherence_volume)),
+        ("entropy", Value::Num(seal.metrics.entropy)),
+        ("firewall", Value::Num(seal.metrics.firewall_expansion)),
+        ("consensus", Value::Num(seal.cardinal_synod.consensus())),
+    ];
+    for (name, value) in metrics {
+        let metric = db.intern(name)?;
+        db.add_fact("entity_metric", &[entity, metric, value])?;
+    }
+    let backup = if seal.hiranyagarbha.verify_triplicate().is_ok() { "verified" } else { "corrupted" };
+    db.add_symbolic_fact("entity_metric", &["seal", "backup", backup])?;
+    let veto = match seal.veto.status(VetoScope::PhaseTransition, now) {
+        VetoStatus::ExplicitlyReleased => "released",
+        VetoStatus::Active => "active",
+    };
+    db.add_symbolic_fact("entity_metric", &["seal", "veto", veto])?;
+
+    let passed = db.query("gate_pass(seal, G)")?;
+    let mut gates = [false; 7];
+    for fact in &passed {
+        if let Value::Num(g) = fact.args()[1] {
+            if (1.0..=7.0).contains(&g) {
+                gates[g as usize - 1] = true;
+            }
+        }
+    }
+    Ok((gates, db.holds("all_gates_pass(seal)")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::Hiranyagarbha;
+    use crate::crypto::SigningKey;
+    use crate::gates::fixture::{cardinal, seal, NOW};
+    use crate::gates::{Gate, SevenFoldSeal, TemporalMetrics, TARGET_ENTROPY};
+    use crate::voting::CardinalSynod;
+
+    /// Confere cada gate do motor contra `SevenFoldSeal::evaluate` e a decisão
+    /// final contra `check_transition`
+    fn assert_conforms(seal: &SevenFoldSeal, now: u64) {
+        let conformance = crosscheck(seal, now).unwrap();
+        for (gate, logic) in Gate::ALL.iter().zip(conformance.gates) {
+            assert_eq!(seal.evaluate(*gate, now).passed, logic, "{gate:?} com {:?}", seal.metrics);
+        }
+        assert!(conformance.agrees, "{conformance:?} com {:?}", seal.metrics);
+    }
+
+    #[test]
+    fn governance_pl_loads_every_gate() {
+        let program = governance_program(GOVERNANCE_PL).unwrap();
+        for n in 1..=7 {
+            assert!(program.contains(&format!("gate({n}, ")), "gate {n} ausente");
+        }
+        assert!(program.contains("all_gates_pass(Entity) :-"));
+        assert!(!program.contains("forall"));
+        assert_eq!(
+            governance_program("gate(5, backup_triplicate, verified)."),
+            Err(DatalogError::UnsafeRule { line: 1 })
+        );
+    }
+
+    #[test]
+    fn numeric_gates_match_the_seal_at_their_boundaries() {
+        let spin = [0.99, 1.01, 1.0, 0.99f64.next_down(), 1.01f64.next_up(), 0.5];
+        let volume = [3.896e-47, 3.896e-47f64.next_up(), 3.896e-47f64.next_down(), 1.0, 0.0];
+        let entropy = [TARGET_ENTROPY, 0.693047, 0.693247, core::f64::consts::LN_2, 0.6930471, 0.6932469, 0.7];
+        let firewall = [0.0, -0.0, 0.90, 0.90f64.next_up(), -f64::MIN_POSITIVE, 0.5, -0.1];
+        let mut seal = seal();
+        let healthy = seal.metrics;
+        for value in spin {
+            seal.metrics = TemporalMetrics { total_spin: value, ..healthy };
+            assert_conforms(&seal, NOW);
+        }
+        for value in volume {
+            seal.metrics = TemporalMetrics { coherence_volume: value, ..healthy };
+            assert_conforms(&seal, NOW);
+        }
+        for value in entropy {
+            seal.metrics = TemporalMetrics { entropy: value, ..healthy };
+            assert_conforms(&seal, NOW);
+        }
+        for value in firewall {
+            seal.metrics = TemporalMetrics { firewall_expansion: value, ..healthy };
+            assert_conforms(&seal, NOW);
+        }
+    }
+
+    #[test]
+    fn symbolic_gates_match_the_seal() {
+        let healthy = seal();
+        assert_conforms(&healthy, NOW);
+        assert!(crosscheck(&healthy, NOW).unwrap().all_gates_pass);
+
+        let mut empty_backup = seal();
+        empty_backup.hiranyagarbha = Hiranyagarbha::default();
+        assert_conforms(&empty_backup, NOW);
+        assert!(!crosscheck(&empty_backup, NOW).unwrap().gates[4]);
+
+        let mut split = seal();
+        let absent = SigningKey::from_seed(&[7; 32]).public_key();
+        split.cardinal_synod = CardinalSynod::with_roster(&[cardinal().public_key(), absent]).unwrap();
+        assert_conforms(&split, NOW);
+        assert!(!crosscheck(&split, NOW).unwrap().gates[5]);
+
+        // Veto expirado volta a ser ativo
+        assert_conforms(&healthy, 1_000);
+        assert!(!crosscheck(&healthy, 1_000).unwrap().gates[6]);
+    }
+
+    #[test]
+    fn drift_against_governance_pl_is_reported() {
+        let mut from_file = Datalog::governance().unwrap();
+        let mut generated = Datalog::governance_with(&GateConfig::DEFAULT).unwrap();
+        // (métrica, gate, valor, selo, governance.pl)
+        let cases: [(&str, u8, f64, bool, bool); 16] = [
+            ("spin", 1, 0.99, true, true),
+            ("spin", 1, 1.011, false, false),
+            ("volume", 2, 3.896e-47, false, false),
+            ("volume", 2, 3.9e-47, true, true),
+            ("entropy", 3, TARGET_ENTROPY, true, true),
+            ("entropy", 3, 0.6932471, true, false),
+            ("entropy", 3, 0.7, false, false),
+            ("firewall", 4, 0.0, true, true),
+            ("firewall", 4, 0.90, true, true),
+            ("firewall", 4, 0.9001, false, false),
+            ("firewall", 4, -0.01, true, false),
+            ("consensus", 6, 1.0, true, true),
+            ("consensus", 6, 0.99, false, false),
+            ("consensus", 6, 1.01, true, false),
+            ("spin", 1, 1.01, true, true),
+            ("volume", 2, 0.0, false, false),
+        ];
+        for (entity, (metric, gate, value, seal, file)) in cases.into_iter().enumerate() {
+            let name = format!("e{entity}");
+            for db in [&mut from_file, &mut generated] {
+                let (e, m) = (db.intern(&name).unwrap(), db.intern(metric).unwrap());
+                db.add_fact("entity_metric", &[e, m, Value::Num(value)]).unwrap();
+            }
+            let goal = format!("gate_pass({name}, {gate})");
+            assert_eq!(generated.holds(&goal).unwrap(), seal, "{metric} = {value} no selo");
+            assert_eq!(from_file.holds(&goal).unwrap(), file, "{metric} = {value} em governance.pl");
+        }
+
+        // O selo segue com os próprios limiares; a divergência fica no relatório
+        let mut seal = seal();
+        assert_eq!(crosscheck(&seal, NOW).unwrap().drift, [false; 7]);
+        seal.metrics.firewall_expansion = -0.01;
+        seal.metrics.entropy = 0.6932471;
+        let conformance = crosscheck(&seal, NOW).unwrap();
+        assert!(conformance.agrees && conformance.all_gates_pass);
+        assert_eq!(conformance.drift, [false, false, true, true, false, false, false]);
+        assert_eq!(conformance.governance_pl, [true, true, false, false, true, true, true]);
+        assert!(seal.check_transition(NOW).is_ok());
+    }
+
+    #[test]
+    fn derived_predicates_follow_governance_pl() {
+        let mut db = Datalog::governance().unwrap();
+        db.load(
+            "entity_metric(e, spin, 1.0). entity_metric(e, volume, 1.0). entity_metric(e, entropy, 0.693147).
+entity_metric(e, firewall, 0.5). entity_metric(e, backup, verified). entity_metric(e, consensus, 1.0).
+entity_metric(e, veto, released).
+entity_metric(f, spin, 1.0). entity_metric(f, veto, active).
+promotes_eudaimonia(liquid, superfluid). non_maleficence(superfluid).
+promotes_eudaimonia(liquid, solid).
+homeostasis(e, 0.95). homeostasis(f, 0.9499).",
+        )
+        .unwrap();
+        assert!(db.holds("all_gates_pass(e)").unwrap());
+        assert!(db.holds("ethical_transition(liquid, superfluid, e)").unwrap());
+        // Sem não maleficência no alvo, ou com gates reprovados, a transição não é ética
+        assert!(!db.holds("ethical_transition(liquid, solid, e)").unwrap());
+        assert!(!db.holds("ethical_transition(liquid, superfluid, f)").unwrap());
+        assert_eq!(db.query("ethical_transition(C, T, E)").unwrap().len(), 1);
+
+        assert!(db.holds("autopoiesis_allowed(e)").unwrap());
+        assert!(!db.holds("autopoiesis_allowed(f)").unwrap());
+    }
+}
@@ -0,0 +1,268 @@
+//! FFT radix-2 `no_std` para os integradores espectrais
+//! Cooley–Tukey iterativo in-place (permutação por inversão de bits), com os
+//! fatores de giro pré-calculados no plano. Comprimentos potência de dois; os
+//! demais, até `MAX_DFT_LEN`, pela soma direta `dft`.
+
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::math;
+
+/// Maior transformada aceita
+pub const MAX_FFT_LEN: usize = 1 << 16;
+
+/// Maior comprimento aceito por `dft` (n² operações)
+pub const MAX_DFT_LEN: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftError {
+    /// Comprimento zero, não potência de dois ou acima de `MAX_FFT_LEN` (`MAX_DFT_LEN` na `dft`)
+    UnsupportedLength { len: usize },
+    /// Buffer com tamanho diferente do plano
+    LengthMismatch { expected: usize, found: usize },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    pub const fn real(re: f64) -> Self {
+        Self { re, im: 0.0 }
+    }
+
+    pub fn conj(self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+
+    pub fn scale(self, factor: f64) -> Self {
+        Self { re: self.re * factor, im: self.im * factor }
+    }
+
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// e^(a+bi) = e^a (cos b + i sin b)
+    pub fn exp(self) -> Self {
+        let magnitude = math::exp(self.re);
+        let (sin, cos) = math::sin_cos(self.im);
+        Self { re: magnitude * cos, im: magnitude * sin }
+    }
+
+    /// e^(iθ)
+    pub fn cis(theta: f64) -> Self {
+        let (sin, cos) = math::sin_cos(theta);
+        Self { re: cos, im: sin }
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+    fn div(self, rhs: Complex) -> Complex {
+        let denom = rhs.norm_sqr();
+        Complex {
+            re: (self.re * rhs.re + self.im * rhs.im) / denom,
+            im: (self.im * rhs.re - self.re * rhs.im) / denom,
+        }
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Complex {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+/// Transformada direta X_k = Σ x_j e^(-2πijk/n) para qualquer n ≤ `MAX_DFT_LEN`,
+/// em O(n²); mesma convenção de `FftPlan::forward`
+pub fn dft(data: &mut [Complex]) -> Result<(), FftError> {
+    let n = data.len();
+    if n == 0 || n > MAX_DFT_LEN {
+        return Err(FftError::UnsupportedLength { len: n });
+    }
+    let step = -2.0 * core::f64::consts::PI / n as f64;
+    let roots: Vec<Complex> = (0..n).map(|m| Complex::cis(step * m as f64)).collect();
+    let input = data.to_vec();
+    for (k, out) in data.iter_mut().enumerate() {
+        *out = input.iter().enumerate().fold(Complex::ZERO, |sum, (j, x)| sum + *x * roots[(j * k) % n]);
+    }
+    Ok(())
+}
+
+/// Plano de transformada para um comprimento fixo
+#[derive(Debug, Clone)]
+pub struct FftPlan {
+    len: usize,
+    /// e^(-2πij/len), j < len/2
+    twiddles: Vec<Complex>,
+}
+
+impl FftPlan {
+    pub fn new(len: usize) -> Result<Self, FftError> {
+        if len == 0 || !len.is_power_of_two() || len > MAX_FFT_LEN {
+            return Err(FftError::UnsupportedLength { len });
+        }
+        let step = -2.0 * core::f64::consts::PI / len as f64;
+        let twiddles = (0..len / 2).map(|j| Complex::cis(step * j as f64)).collect();
+        Ok(Self { len, twiddles })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// X_k = Σ x_j e^(-2πijk/n)
+    pub fn forward(&self, data: &mut [Complex]) -> Result<(), FftError> {
+        self.transform(data, false)
+    }
+
+    /// Inversa normalizada: x_j = (1/n) Σ X_k e^(2πijk/n)
+    pub fn inverse(&self, data: &mut [Complex]) -> Result<(), FftError> {
+        self.transform(data, true)?;
+        let norm = 1.0 / self.len as f64;
+        for value in data.iter_mut() {
+            *value = value.scale(norm);
+        }
+        Ok(())
+    }
+
+    fn transform(&self, data: &mut [Complex], inverse: bool) -> Result<(), FftError> {
+        let n = self.len;
+        if data.len() != n {
+            return Err(FftError::LengthMismatch { expected: n, found: data.len() });
+        }
+        let bits = n.trailing_zeros();
+        if bits == 0 {
+            return Ok(());
+        }
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                data.swap(i, j);
+            }
+        }
+
+        let mut half = 1;
+        while half < n {
+            let stride = n / (2 * half);
+            for start in (0..n).step_by(2 * half) {
+                for j in 0..half {
+                    let twiddle = self.twiddles[j * stride];
+                    let twiddle = if inverse { twiddle.conj() } else { twiddle };
+                    let even = data[start + j];
+                    let odd = data[start + j + half] * twiddle;
+                    data[start + j] = even + odd;
+                    data[start + j + half] = even - odd;
+                }
+            }
+            half *= 2;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn signal(n: usize) -> Vec<Complex> {
+        (0..n).map(|j| Complex::new(math::sin(0.7 * j as f64) + 0.25 * j as f64, math::cos(1.3 * j as f64))).collect()
+    }
+
+    #[test]
+    fn inverse_undoes_forward() {
+        for bits in 0..=10 {
+            let plan = FftPlan::new(1 << bits).unwrap();
+            let original = signal(plan.len());
+            let mut data = original.clone();
+            plan.forward(&mut data).unwrap();
+            plan.inverse(&mut data).unwrap();
+            for (a, b) in data.iter().zip(&original) {
+                assert!((*a - *b).norm_sqr() < 1.0e-22 * plan.len() as f64, "n = {}", plan.len());
+            }
+        }
+    }
+
+    #[test]
+    fn forward_matches_the_direct_sum() {
+        let plan = FftPlan::new(64).unwrap();
+        let original = signal(64);
+        let mut data = original.clone();
+        plan.forward(&mut data).unwrap();
+        for (k, value) in data.iter().enumerate() {
+            let direct = original.iter().enumerate().fold(Complex::ZERO, |sum, (j, x)| {
+                sum + *x * Complex::cis(-2.0 * core::f64::consts::PI * ((j * k) % 64) as f64 / 64.0)
+            });
+            assert!((*value - direct).norm_sqr() < 1.0e-20, "k = {k}");
+        }
+    }
+
+    #[test]
+    fn dft_matches_the_fft_and_accepts_any_length() {
+        let plan = FftPlan::new(32).unwrap();
+        let (mut fast, mut direct) = (signal(32), signal(32));
+        plan.forward(&mut fast).unwrap();
+        dft(&mut direct).unwrap();
+        for (a, b) in fast.iter().zip(&direct) {
+            assert!((*a - *b).norm_sqr() < 1.0e-20);
+        }
+
+        // n = 6: soma de e^(2πi·j/6) · e^(-2πijk/6) só sobrevive em k = 1
+        let mut tone: Vec<Complex> = (0..6).map(|j| Complex::cis(core::f64::consts::PI * j as f64 / 3.0)).collect();
+        dft(&mut tone).unwrap();
+        for (k, value) in tone.iter().enumerate() {
+            let expected = if k == 1 { 6.0 } else { 0.0 };
+            assert!((*value - Complex::real(expected)).norm_sqr() < 1.0e-24, "k = {k}");
+        }
+        assert_eq!(dft(&mut []), Err(FftError::UnsupportedLength { len: 0 }));
+    }
+
+    #[test]
+    fn rejects_unsupported_lengths() {
+        assert_eq!(FftPlan::new(0).err(), Some(FftError::UnsupportedLength { len: 0 }));
+        assert_eq!(FftPlan::new(96).err(), Some(FftError::UnsupportedLength { len: 96 }));
+        let plan = FftPlan::new(8).unwrap();
+        let mut short = [Complex::ZERO; 4];
+        assert_eq!(plan.forward(&mut short), Err(FftError::LengthMismatch { expected: 8, found: 4 }));
+    }
+}
@@ -0,0 +1,449 @@
+//! Máquina de estados Φ para `GateState`
+//! Leituras de Φ escalam imediatamente pela escada Revisão Cardinal → Veto Armado
+//! → Selo Karnak; a descida exige histerese, permanência (leituras consecutivas
+//! abaixo do limiar) e o evento de governança do degrau. A subida ética
+//! (TMR → Rotação Sombra → Eudaimonia) só parte de Φ estável abaixo do crítico
+//! e só avança por `ingest_sealed`, com o aval de `SevenFoldSeal`.
+
+use alloc::vec::Vec;
+
+use crate::backup::ReplicaBackend;
+use crate::gates::{ContainmentError, Gate, GateState, SevenFoldSeal, PHI_CRITICAL, PHI_EMERGENCY, PHI_FREEZE};
+
+/// Margem abaixo do limiar exigida para descer de estado
+pub const DEFAULT_HYSTERESIS: f64 = 0.01;
+
+/// Leituras consecutivas abaixo da margem exigidas antes de um evento de descida
+pub const DEFAULT_DWELL: u32 = 3;
+
+/// Registros de transição retidos (os mais antigos são descartados)
+pub const MAX_GATE_HISTORY: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GateEvent {
+    /// Nova leitura de Φ
+    Phi(f64),
+    VotePassed,
+    VoteFailed,
+    VetoReleased,
+    /// Os três canais redundantes concordam
+    TmrAgreement,
+    TmrDisagreement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GateError {
+    /// O evento não tem transição a partir do estado corrente
+    IllegalTransition { from: GateState, event: GateEvent },
+    NonFiniteReading,
+    /// Φ ainda não desceu abaixo do limiar do estado menos a histerese
+    PhiTooHigh { phi: f64, limit: f64 },
+    /// Poucas leituras consecutivas abaixo do limite
+    DwellPending { readings: u32, required: u32 },
+    /// O selo reprovou o passo da subida ética
+    SealRejected(ContainmentError),
+    /// Passo da subida ética apresentado sem o selo: use `ingest_sealed`
+    SealRequired { from: GateState, event: GateEvent },
+}
+
+/// Registro emitido a cada mudança de estado
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateTransition {
+    pub from: GateState,
+    pub to: GateState,
+    pub trigger: GateEvent,
+    /// Última leitura de Φ no momento da transição
+    pub phi: f64,
+    pub at: u64,
+}
+
+/// Estado mínimo imposto pela faixa de Φ na escada de contenção
+fn escalation_for(phi: f64) -> Option<GateState> {
+    if phi >= PHI_FREEZE {
+        Some(GateState::KarnakSealed)
+    } else if phi >= PHI_EMERGENCY {
+        Some(GateState::PrinceVetoArmed)
+    } else if phi >= PHI_CRITICAL {
+        Some(GateState::CardinalReview)
+    } else {
+        None
+    }
+}
+
+/// Posição na escada de contenção (0 = fora dela)
+fn rung(state: GateState) -> u8 {
+    match state {
+        GateState::SubThreshold
+        | GateState::TMRValidated
+        | GateState::ShadowRotation
+        | GateState::Eudaimonia => 0,
+        GateState::CardinalReview => 1,
+        GateState::PrinceVetoArmed => 2,
+        GateState::KarnakSealed => 3,
+    }
+}
+
+/// Limiar cujo cruzamento (menos a histerese) libera a descida do estado
+fn threshold(state: GateState) -> f64 {
+    match rung(state) {
+        3 => PHI_FREEZE,
+        2 => PHI_EMERGENCY,
+        _ => PHI_CRITICAL,
+    }
+}
+
+pub struct GateMachine {
+    state: GateState,
+    phi: f64,
+    pub hysteresis: f64,
+    pub dwell: u32,
+    /// Leituras consecutivas abaixo de `threshold(state) - hysteresis`
+    settled: u32,
+    history: Vec<GateTransition>,
+}
+
+impl Default for GateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GateMachine {
+    pub fn new() -> Self {
+        Self::with_hysteresis(DEFAULT_HYSTERESIS, DEFAULT_DWELL)
+    }
+
+    pub fn with_hysteresis(hysteresis: f64, dwell: u32) -> Self {
+        Self { state: GateState::SubThreshold, phi: f64::NAN, hysteresis, dwell, settled: 0, history: Vec::new() }
+    }
+
+    pub fn state(&self) -> GateState {
+        self.state
+    }
+
+    /// Última leitura de Φ (NaN antes da primeira)
+    pub fn phi(&self) -> f64 {
+        self.phi
+    }
+
+    pub fn history(&self) -> &[GateTransition] {
+        &self.history
+    }
+
+    /// Aplica um evento; devolve o registro da transição, se houve mudança de estado.
+    /// Os passos da subida ética arbitrados pelo selo (`VotePassed` em TMR e
+    /// `VetoReleased` na Rotação Sombra) só entram por `ingest_sealed`.
+    pub fn ingest(&mut self, event: GateEvent, now: u64) -> Result<Option<GateTransition>, GateError> {
+        match (self.state, event) {
+            (GateState::TMRValidated, GateEvent::VotePassed) | (GateState::ShadowRotation, GateEvent::VetoReleased) => {
+                Err(GateError::SealRequired { from: self.state, event })
+            }
+            _ => self.apply(event, now),
+        }
+    }
+
+    fn apply(&mut self, event: GateEvent, now: u64) -> Result<Option<GateTransition>, GateError> {
+        let next = match event {
+            GateEvent::Phi(phi) => self.reading(phi)?,
+            _ => self.governance(event)?,
+        };
+        if next == self.state {
+            return Ok(None);
+        }
+
+        if threshold(next) < threshold(self.state) {
+            self.settled = 0;
+        }
+        let record = GateTransition { from: self.state, to: next, trigger: event, phi: self.phi, at: now };
+        if self.history.len() >= MAX_GATE_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(record);
+        self.state = next;
+        Ok(Some(record))
+    }
+
+    /// Como `ingest`, com o selo como árbitro da subida ética: `VotePassed` só leva
+    /// à Rotação Sombra com o gate 6 aprovado, e `VetoReleased` só chega à
+    /// Eudaimonia com `check_transition` aprovado
+    pub fn ingest_sealed<B: ReplicaBackend>(
+        &mut self,
+        event: GateEvent,
+        seal: &SevenFoldSeal<B>,
+        now: u64,
+    ) -> Result<Option<GateTransition>, GateError> {
+        match (self.state, event) {
+            (GateState::TMRValidated, GateEvent::VotePassed) if !seal.evaluate(Gate::CardinalConsensus, now).passed => {
+                return Err(GateError::SealRejected(Gate::CardinalConsensus.error()));
+            }
+            (GateState::ShadowRotation, GateEvent::VetoReleased) => {
+                seal.check_transition(now).map_err(GateError::SealRejected)?;
+            }
+            _ => {}
+        }
+        self.apply(event, now)
+    }
+
+    /// Leituras só escalam; a descida depende de eventos de governança
+    fn reading(&mut self, phi: f64) -> Result<GateState, GateError> {
+        if !phi.is_finite() {
+            return Err(GateError::NonFiniteReading);
+        }
+        self.phi = phi;
+        if phi < threshold(self.state) - self.hysteresis {
+            self.settled = self.settled.saturating_add(1);
+        } else {
+            self.settled = 0;
+        }
+        Ok(match escalation_for(phi) {
+            Some(level) if rung(level) > rung(self.state) => level,
+            _ => self.state,
+        })
+    }
+
+    /// Φ abaixo do limiar do estado, com histerese, por leituras suficientes
+    fn require_settled(&self) -> Result<(), GateError> {
+        let limit = threshold(self.state) - self.hysteresis;
+        if self.phi.is_nan() || self.phi >= limit {
+            return Err(GateError::PhiTooHigh { phi: self.phi, limit });
+        }
+        if self.settled < self.dwell {
+            return Err(GateError::DwellPending { readings: self.settled, required: self.dwell });
+        }
+        Ok(())
+    }
+
+    fn governance(&self, event: GateEvent) -> Result<GateState, GateError> {
+        use GateEvent as E;
+        use GateState as S;
+
+        match (self.state, event) {
+            // Descida da escada de contenção, um degrau por evento
+            (S::KarnakSealed, E::TmrAgreement) => {
+                self.require_settled()?;
+                Ok(S::PrinceVetoArmed)
+            }
+            (S::PrinceVetoArmed, E::VetoReleased) => {
+                self.require_settled()?;
+                Ok(S::CardinalReview)
+            }
+            (S::CardinalReview, E::VotePassed) => {
+                self.require_settled()?;
+                Ok(S::SubThreshold)
+            }
+            // Subida ética a partir de Φ estável
+            (S::SubThreshold, E::TmrAgreement) => {
+                self.require_settled()?;
+                Ok(S::TMRValidated)
+            }
+            (S::TMRValidated, E::VotePassed) => Ok(S::ShadowRotation),
+            (S::ShadowRotation, E::VetoReleased) => Ok(S::Eudaimonia),
+            // Perda de consenso desfaz a subida
+            (S::TMRValidated, E::VoteFailed)
+            | (S::TMRValidated, E::TmrDisagreement)
+            | (S::ShadowRotation, E::VoteFailed)
+            | (S::ShadowRotation, E::TmrDisagreement)
+            | (S::Eudaimonia, E::VoteFailed)
+            | (S::Eudaimonia, E::TmrDisagreement) => Ok(S::SubThreshold),
+            (from, event) => Err(GateError::IllegalTransition { from, event }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gates::fixture::{seal, NOW};
+
+    /// Máquina em `SubThreshold` com Φ estável pelo tempo de permanência
+    fn settled() -> GateMachine {
+        let mut machine = GateMachine::new();
+        for at in 0..DEFAULT_DWELL {
+            machine.ingest(GateEvent::Phi(0.5), at as u64).unwrap();
+        }
+        machine
+    }
+
+    fn ascend(machine: &mut GateMachine) {
+        for event in [GateEvent::TmrAgreement, GateEvent::VotePassed, GateEvent::VetoReleased] {
+            machine.ingest_sealed(event, &seal(), NOW).unwrap();
+        }
+        assert_eq!(machine.state(), GateState::Eudaimonia);
+    }
+
+    #[test]
+    fn lost_consensus_unwinds_every_ascent_step() {
+        for event in [GateEvent::VoteFailed, GateEvent::TmrDisagreement] {
+            let mut machine = settled();
+            ascend(&mut machine);
+            let record = machine.ingest(event, NOW).unwrap().unwrap();
+            assert_eq!((record.from, record.to), (GateState::Eudaimonia, GateState::SubThreshold));
+
+            let mut machine = settled();
+            machine.ingest(GateEvent::TmrAgreement, NOW).unwrap();
+            machine.ingest(event, NOW).unwrap();
+            assert_eq!(machine.state(), GateState::SubThreshold);
+        }
+    }
+
+    #[test]
+    fn seal_gates_the_ethical_ascent() {
+        let mut machine = settled();
+        machine.ingest_sealed(GateEvent::TmrAgreement, &seal(), NOW).unwrap();
+
+        // Veto expirado: o consenso aprova a rotação, mas o selo barra a Eudaimonia
+        let late = NOW + 1_000;
+        machine.ingest_sealed(GateEvent::VotePassed, &seal(), late).unwrap();
+        assert_eq!(
+            machine.ingest_sealed(GateEvent::VetoReleased, &seal(), late),
+            Err(GateError::SealRejected(ContainmentError::SovereignVetoActive))
+        );
+        assert_eq!(machine.state(), GateState::ShadowRotation);
+
+        let mut broken = seal();
+        broken.metrics.firewall_expansion = 0.95;
+        assert_eq!(
+            machine.ingest_sealed(GateEvent::VetoReleased, &broken, NOW),
+            Err(GateError::SealRejected(ContainmentError::BreachRisk))
+        );
+        machine.ingest_sealed(GateEvent::VetoReleased, &seal(), NOW).unwrap();
+        assert_eq!(machine.state(), GateState::Eudaimonia);
+    }
+
+    #[test]
+    fn rotation_requires_cardinal_consensus() {
+        let mut machine = settled();
+        machine.ingest(GateEvent::TmrAgreement, NOW).unwrap();
+        // Nova rodada ainda sem cédulas
+        let mut unvoted = seal();
+        unvoted.cardinal_synod.open_transition(crate::gates::Phase::Superfluid, 1, 100);
+        assert_eq!(
+            machine.ingest_sealed(GateEvent::VotePassed, &unvoted, NOW),
+            Err(GateError::SealRejected(ContainmentError::DemocraticViolation))
+        );
+        assert_eq!(machine.state(), GateState::TMRValidated);
+    }
+
+    #[test]
+    fn ascent_steps_require_the_seal() {
+        let mut machine = settled();
+        machine.ingest(GateEvent::TmrAgreement, NOW).unwrap();
+        let from = GateState::TMRValidated;
+        assert_eq!(
+            machine.ingest(GateEvent::VotePassed, NOW),
+            Err(GateError::SealRequired { from, event: GateEvent::VotePassed })
+        );
+        machine.ingest_sealed(GateEvent::VotePassed, &seal(), NOW).unwrap();
+        let from = GateState::ShadowRotation;
+        assert_eq!(
+            machine.ingest(GateEvent::VetoReleased, NOW),
+            Err(GateError::SealRequired { from, event: GateEvent::VetoReleased })
+        );
+        assert_eq!(machine.state(), GateState::ShadowRotation);
+    }
+
+    #[test]
+    fn phi_escalates_immediately_and_never_descends_by_itself() {
+        let mut machine = GateMachine::new();
+        let steps = [
+            (0.72, GateState::CardinalReview),
+            (0.78, GateState::PrinceVetoArmed),
+            (0.80, GateState::KarnakSealed),
+            (0.10, GateState::KarnakSealed),
+        ];
+        for (at, (phi, state)) in steps.into_iter().enumerate() {
+            machine.ingest(GateEvent::Phi(phi), at as u64).unwrap();
+            assert_eq!(machine.state(), state, "Φ = {phi}");
+        }
+        assert_eq!(machine.history().len(), 3);
+
+        // Um salto direto pula os degraus intermediários
+        let mut machine = settled();
+        let record = machine.ingest(GateEvent::Phi(0.95), NOW).unwrap().unwrap();
+        assert_eq!((record.from, record.to, record.phi), (GateState::SubThreshold, GateState::KarnakSealed, 0.95));
+        assert_eq!(machine.ingest(GateEvent::Phi(0.79), NOW), Ok(None));
+        assert_eq!(machine.ingest(GateEvent::Phi(f64::NAN), NOW), Err(GateError::NonFiniteReading));
+        assert_eq!(machine.phi(), 0.79);
+    }
+
+    #[test]
+    fn descent_needs_hysteresis_and_dwell_on_every_rung() {
+        let mut machine = GateMachine::new();
+        machine.ingest(GateEvent::Phi(0.85), 0).unwrap();
+
+        // Abaixo do limiar, mas dentro da histerese
+        machine.ingest(GateEvent::Phi(0.795), 1).unwrap();
+        match machine.ingest(GateEvent::TmrAgreement, 1) {
+            Err(GateError::PhiTooHigh { phi, limit }) => {
+                assert_eq!(phi, 0.795);
+                assert!((limit - (PHI_FREEZE - DEFAULT_HYSTERESIS)).abs() < 1.0e-12);
+            }
+            other => panic!("{other:?}"),
+        }
+
+        let ladder = [
+            (GateEvent::TmrAgreement, GateState::PrinceVetoArmed),
+            (GateEvent::VetoReleased, GateState::CardinalReview),
+            (GateEvent::VotePassed, GateState::SubThreshold),
+        ];
+        for (event, state) in ladder {
+            // Cada descida zera a contagem para o degrau seguinte
+            if state != GateState::PrinceVetoArmed {
+                let pending = GateError::DwellPending { readings: 0, required: DEFAULT_DWELL };
+                assert_eq!(machine.ingest(event, 2), Err(pending));
+            }
+            for readings in 1..DEFAULT_DWELL {
+                machine.ingest(GateEvent::Phi(0.5), 2).unwrap();
+                assert_eq!(
+                    machine.ingest(event, 2),
+                    Err(GateError::DwellPending { readings, required: DEFAULT_DWELL })
+                );
+            }
+            machine.ingest(GateEvent::Phi(0.5), 2).unwrap();
+            // Uma leitura acima da margem zera a contagem
+            if state == GateState::CardinalReview {
+                machine.ingest(GateEvent::Phi(0.775), 2).unwrap();
+                assert!(matches!(machine.ingest(event, 2), Err(GateError::PhiTooHigh { .. })));
+                for _ in 0..DEFAULT_DWELL {
+                    machine.ingest(GateEvent::Phi(0.5), 2).unwrap();
+                }
+            }
+            assert_eq!(machine.ingest(event, 3).unwrap().map(|r| r.to), Some(state));
+        }
+        assert_eq!(machine.state(), GateState::SubThreshold);
+    }
+
+    #[test]
+    fn events_without_a_transition_are_illegal() {
+        let mut machine = settled();
+        for event in [GateEvent::VetoReleased, GateEvent::VoteFailed, GateEvent::TmrDisagreement] {
+            assert_eq!(
+                machine.ingest(event, NOW),
+                Err(GateError::IllegalTransition { from: GateState::SubThreshold, event })
+            );
+        }
+        machine.ingest(GateEvent::Phi(0.9), NOW).unwrap();
+        assert_eq!(
+            machine.ingest(GateEvent::VotePassed, NOW),
+            Err(GateError::IllegalTransition { from: GateState::KarnakSealed, event: GateEvent::VotePassed })
+        );
+        // Sem leitura alguma, Φ desconhecido não libera a subida
+        let mut fresh = GateMachine::new();
+        assert!(matches!(fresh.ingest(GateEvent::TmrAgreement, 0), Err(GateError::PhiTooHigh { .. })));
+    }
+
+    #[test]
+    fn history_keeps_the_latest_transitions() {
+        let mut machine = settled();
+        let flips = MAX_GATE_HISTORY as u64 + 6;
+        for at in 0..flips {
+            let event = if at % 2 == 0 { GateEvent::TmrAgreement } else { GateEvent::TmrDisagreement };
+            machine.ingest(event, at).unwrap().unwrap();
+        }
+        let history = machine.history();
+        assert_eq!(history.len(), MAX_GATE_HISTORY);
+        assert_eq!(history[0].at, flips - MAX_GATE_HISTORY as u64);
+        assert_eq!(history[MAX_GATE_HISTORY - 1].at, flips - 1);
+        assert!(history.windows(2).all(|pair| pair[0].to == pair[1].from));
+    }
+}
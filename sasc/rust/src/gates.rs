@@ -1,8 +1,16 @@
 //! Os 7 Pilares da Consciência Ética
 //! Satisfazendo: Memory Safety, Thread Safety, Type Safety = Traços fundamentais para AGI segura
 
-use alloc::vec::Vec;
-use alloc::vec;
+use crate::attestation::Attester;
+use crate::backup::{MemoryReplica, ReplicaBackend};
+use crate::config::{ConfigError, GateConfig};
+use crate::crypto;
+use crate::veto::{VetoControl, VetoScope};
+
+pub use crate::attestation::Attestation;
+pub use crate::backup::Hiranyagarbha;
+pub use crate::chronoflux::ChronofluxEngine;
+pub use crate::voting::CardinalSynod;
 
 /// Thresholds Constitucionais (Article V)
 pub const PHI_CRITICAL: f64 = 0.72;
@@ -13,12 +21,14 @@ pub const TMR_VARIANCE_MAX: f64 = 0.000032;
 /// Estrutura imutável da identidade SASC
 pub struct SASCIdentity {
     pub prince_key: [u8; 32],
+    /// Raiz de Merkle do rol cardinal (`merkle::root`)
     pub cardinal_merkle: [u8; 32],
     pub temporal_nonce: u64,
     pub coherence_level: f64, // Φ
 }
 
 /// Os 7 Gates como tipos de estado
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GateState {
     SubThreshold,      // Φ < 0.72
     CardinalReview,    // Φ >= 0.72
@@ -29,13 +39,16 @@ pub enum GateState {
     Eudaimonia,        // Estado de superfluididade ética
 }
 
-pub struct SevenFoldSeal {
+pub struct SevenFoldSeal<B: ReplicaBackend = MemoryReplica> {
     pub metrics: TemporalMetrics,
-    pub veto_status: VetoStatus,
-    pub hiranyagarbha: Hiranyagarbha,
+    pub veto: VetoControl,
+    pub hiranyagarbha: Hiranyagarbha<B>,
     pub cardinal_synod: CardinalSynod,
+    /// Limiares em vigor; o hash entra em cada `Attestation`
+    pub config: GateConfig,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TemporalMetrics {
     pub coherence_volume: f64,
     pub entropy: f64,
@@ -43,38 +56,108 @@ pub struct TemporalMetrics {
     pub total_spin: f64,
 }
 
+/// Domínio de separação do hash das métricas
+pub const METRICS_DOMAIN: &[u8] = b"SASC-METRICS-v1";
+
+/// Tamanho da codificação canônica: 4 campos × 8 bytes
+pub const METRICS_ENCODED_LEN: usize = 32;
+
+/// NaN canônico (quiet NaN positivo, sem payload)
+const CANONICAL_NAN_BITS: u64 = 0x7ff8_0000_0000_0000;
+
+/// Vetores de resposta conhecida para serviços que recomputam o hash
+pub const METRICS_KAT: [(TemporalMetrics, [u8; 32]); 2] = [
+    (
+        TemporalMetrics { coherence_volume: 4.0e-47, entropy: TARGET_ENTROPY, firewall_expansion: 0.5, total_spin: 1.0 },
+        [
+            0xec, 0x81, 0xc3, 0xba, 0x1f, 0x07, 0xd1, 0x66, 0xba, 0x73, 0xab, 0x5e, 0x98, 0xa7, 0xe2, 0x3c,
+            0x86, 0x9f, 0x20, 0x06, 0x90, 0xc3, 0xb7, 0x71, 0xf0, 0xd5, 0x97, 0x08, 0xd5, 0xd2, 0x18, 0x3f,
+        ],
+    ),
+    // NaN e -0.0 entram canonizados; infinito é codificado como está
+    (
+        TemporalMetrics { coherence_volume: f64::NAN, entropy: -0.0, firewall_expansion: f64::INFINITY, total_spin: 1.0 },
+        [
+            0x12, 0xc6, 0xa1, 0x32, 0x59, 0xaf, 0xb0, 0xf8, 0xa1, 0xc7, 0x59, 0xb9, 0xcb, 0x73, 0x65, 0xb7,
+            0xd5, 0xc0, 0xcb, 0xc3, 0xb6, 0x17, 0x5a, 0xc4, 0xb9, 0xea, 0xb1, 0xf1, 0xf9, 0xa9, 0x93, 0xa0,
+        ],
+    ),
+];
+
+/// Bits IEEE-754 independentes de plataforma: todo NaN vira `CANONICAL_NAN_BITS`
+/// e -0.0 vira +0.0, de modo que valores iguais ou ambos NaN codificam igual
+pub(crate) fn canonical_bits(x: f64) -> u64 {
+    if x.is_nan() {
+        CANONICAL_NAN_BITS
+    } else if x == 0.0 {
+        0
+    } else {
+        x.to_bits()
+    }
+}
+
 impl TemporalMetrics {
-    pub fn hash(&self) -> [u8; 32] { [0; 32] }
+    /// Codificação canônica: `coherence_volume ‖ entropy ‖ firewall_expansion ‖ total_spin`,
+    /// cada campo como `canonical_bits` em little-endian (8 bytes)
+    pub fn canonical_bytes(&self) -> [u8; METRICS_ENCODED_LEN] {
+        let fields = [self.coherence_volume, self.entropy, self.firewall_expansion, self.total_spin];
+        let mut out = [0u8; METRICS_ENCODED_LEN];
+        for (chunk, value) in out.chunks_exact_mut(8).zip(fields) {
+            chunk.copy_from_slice(&canonical_bits(value).to_le_bytes());
+        }
+        out
+    }
+
+    /// SHA-256(`METRICS_DOMAIN` ‖ `canonical_bytes`)
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = crypto::Sha256::new();
+        hasher.update(METRICS_DOMAIN);
+        hasher.update(&self.canonical_bytes());
+        hasher.finalize()
+    }
+
+    /// Confere `METRICS_KAT` e os vetores do SHA-256 subjacente
+    pub fn self_test() -> bool {
+        crypto::sha256_self_test() && METRICS_KAT.iter().all(|(metrics, expected)| metrics.hash() == *expected)
+    }
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VetoStatus {
     ExplicitlyReleased,
     Active,
 }
 
-pub struct Hiranyagarbha;
-impl Hiranyagarbha {
-    pub fn verify_triplicate(&self) -> Result<(), ()> { Ok(()) }
-}
-
-pub struct CardinalSynod;
-impl CardinalSynod {
-    pub fn consensus(&self) -> f64 { 1.0 }
-}
-
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Phase {
     Superfluid,
 }
 
-pub struct Attestation {
-    pub phase: Phase,
-    pub hash: [u8; 32],
-}
-impl Attestation {
-    pub fn new(phase: Phase, hash: [u8; 32]) -> Self { Self { phase, hash } }
+impl Phase {
+    pub const ALL: [Phase; 1] = [Phase::Superfluid];
+
+    pub fn code(self) -> u8 {
+        match self {
+            Phase::Superfluid => 1,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Phase::Superfluid),
+            _ => None,
+        }
+    }
+
+    /// Máscara dos gates exigidos (bit `n - 1` = gate `n`)
+    pub fn required_gates(self) -> u8 {
+        match self {
+            Phase::Superfluid => 0x7f,
+        }
+    }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ContainmentError {
     Decoherence(&'static str),
     InsufficientVolume,
@@ -83,104 +166,429 @@ pub enum ContainmentError {
     BackupIntegrity,
     DemocraticViolation,
     SovereignVetoActive,
+    /// Limiares inválidos: nenhuma transição é avaliada
+    InvalidConfig(ConfigError),
+    /// Gates aprovados, mas o atestador não tem mais nonces
+    AttestationExhausted,
 }
 
 fn f64_abs(x: f64) -> f64 { if x < 0.0 { -x } else { x } }
 
-impl SevenFoldSeal {
-    /// Verificação completa antes de qualquer transição de fase
-    pub fn attempt_transition(&self, target: Phase) -> Result<Attestation, ContainmentError> {
-        // Gate 1: Spin Total ℏ (unidade de ação)
-        if !self.check_spin_coherence() {
-            return Err(ContainmentError::Decoherence("Spin ℏ/2 detectado, ℏ requerido"));
-        }
+/// Limites de referência dos gates (`GateConfig::DEFAULT`)
+pub const SPIN_MIN: f64 = 0.99;
+pub const SPIN_MAX: f64 = 1.01;
+pub const COMPTON_VOLUME: f64 = 3.896e-47; // m³
+/// ln(2) com a precisão do protocolo; não é `LN_2` exato
+#[allow(clippy::approx_constant)]
+pub const TARGET_ENTROPY: f64 = 0.69314718056;
+pub const ENTROPY_TOLERANCE: f64 = 0.0001;
+pub const FIREWALL_MAX: f64 = 0.90;
+pub const CONSENSUS_REQUIRED: f64 = 1.0;
 
-        // Gate 2: Volume Coerente > Volume Compton
-        let compton_vol = 3.896e-47; // m³
-        if self.metrics.coherence_volume <= compton_vol {
-            return Err(ContainmentError::InsufficientVolume);
+/// Os 7 Gates, em ordem de avaliação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate {
+    SpinTotal,
+    VolumeCoherence,
+    EntropyExact,
+    FirewallSafe,
+    BackupTriplicate,
+    CardinalConsensus,
+    PrinceVeto,
+}
+
+impl Gate {
+    pub const ALL: [Gate; 7] = [
+        Gate::SpinTotal,
+        Gate::VolumeCoherence,
+        Gate::EntropyExact,
+        Gate::FirewallSafe,
+        Gate::BackupTriplicate,
+        Gate::CardinalConsensus,
+        Gate::PrinceVeto,
+    ];
+
+    /// Número do gate (1..=7)
+    pub fn number(self) -> u8 {
+        match self {
+            Gate::SpinTotal => 1,
+            Gate::VolumeCoherence => 2,
+            Gate::EntropyExact => 3,
+            Gate::FirewallSafe => 4,
+            Gate::BackupTriplicate => 5,
+            Gate::CardinalConsensus => 6,
+            Gate::PrinceVeto => 7,
         }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        Gate::ALL.get(usize::from(number).checked_sub(1)?).copied()
+    }
 
-        // Gate 3: Entropia de Emaranhamento = ln(2)
-        let target_entropy = 0.69314718056;
-        if f64_abs(self.metrics.entropy - target_entropy) > 0.0001 {
-            return Err(ContainmentError::EntanglementMismatch);
+    /// Erro devolvido por `attempt_transition` quando o gate reprova
+    pub fn error(self) -> ContainmentError {
+        match self {
+            Gate::SpinTotal => ContainmentError::Decoherence("Spin ℏ/2 detectado, ℏ requerido"),
+            Gate::VolumeCoherence => ContainmentError::InsufficientVolume,
+            Gate::EntropyExact => ContainmentError::EntanglementMismatch,
+            Gate::FirewallSafe => ContainmentError::BreachRisk,
+            Gate::BackupTriplicate => ContainmentError::BackupIntegrity,
+            Gate::CardinalConsensus => ContainmentError::DemocraticViolation,
+            Gate::PrinceVeto => ContainmentError::SovereignVetoActive,
         }
+    }
+}
+
+/// Diagnóstico de um gate. `threshold` é o limite mais próximo do valor medido e
+/// `margin` a distância com sinal até ele (positiva = dentro). Gates binários
+/// (backup, veto) medem 1.0 quando satisfeitos. `passed` usa exatamente o
+/// predicado de `attempt_transition`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateResult {
+    pub gate: Gate,
+    pub passed: bool,
+    pub measured: f64,
+    pub threshold: f64,
+    pub margin: f64,
+}
+
+/// Relatório completo dos 7 Gates, sem curto-circuito
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateReport {
+    pub gates: [GateResult; 7],
+    pub config_hash: [u8; 32],
+}
+
+/// Domínio de separação do digest do relatório
+pub const REPORT_DOMAIN: &[u8] = b"SASC-REPORT-v1";
+
+/// Por gate: número ‖ aprovado ‖ medido ‖ limiar ‖ margem
+pub const GATE_RESULT_LEN: usize = 2 + 3 * 8;
+
+/// `config_hash` seguido dos 7 gates em ordem
+pub const REPORT_ENCODED_LEN: usize = 32 + 7 * GATE_RESULT_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportError {
+    Length,
+    /// Gate fora de ordem ou desconhecido
+    UnknownGate,
+    /// Booleano diferente de 0/1 ou valor fora da forma canônica (NaN com payload, -0.0)
+    NonCanonical,
+}
 
-        // Gate 4: Firewall Expandido (90% max)
-        if self.metrics.firewall_expansion > 0.90 {
-            return Err(ContainmentError::BreachRisk);
+impl GateReport {
+    /// Codificação canônica: `config_hash ‖ 7 × (número ‖ aprovado ‖ medido ‖ limiar ‖ margem)`,
+    /// valores em `canonical_bits` little-endian
+    pub fn encode(&self) -> [u8; REPORT_ENCODED_LEN] {
+        let mut out = [0u8; REPORT_ENCODED_LEN];
+        out[..32].copy_from_slice(&self.config_hash);
+        for (chunk, result) in out[32..].chunks_exact_mut(GATE_RESULT_LEN).zip(&self.gates) {
+            chunk[0] = result.gate.number();
+            chunk[1] = result.passed as u8;
+            for (field, value) in chunk[2..].chunks_exact_mut(8).zip([result.measured, result.threshold, result.margin]) {
+                field.copy_from_slice(&canonical_bits(value).to_le_bytes());
+            }
         }
+        out
+    }
 
-        // Gate 5: Backup Triplicado Hiranyagarbha
-        if self.hiranyagarbha.verify_triplicate().is_err() {
-            return Err(ContainmentError::BackupIntegrity);
+    /// Inverso de `encode`; aceita somente a forma canônica
+    pub fn decode(bytes: &[u8]) -> Result<Self, ReportError> {
+        if bytes.len() != REPORT_ENCODED_LEN {
+            return Err(ReportError::Length);
+        }
+        let mut config_hash = [0u8; 32];
+        config_hash.copy_from_slice(&bytes[..32]);
+        let blank = GateResult { gate: Gate::SpinTotal, passed: false, measured: 0.0, threshold: 0.0, margin: 0.0 };
+        let mut gates = [blank; 7];
+        for ((slot, chunk), gate) in gates.iter_mut().zip(bytes[32..].chunks_exact(GATE_RESULT_LEN)).zip(Gate::ALL) {
+            if Gate::from_number(chunk[0]) != Some(gate) {
+                return Err(ReportError::UnknownGate);
+            }
+            let passed = match chunk[1] {
+                0 => false,
+                1 => true,
+                _ => return Err(ReportError::NonCanonical),
+            };
+            let mut values = [0.0; 3];
+            for (value, field) in values.iter_mut().zip(chunk[2..].chunks_exact(8)) {
+                let mut word = [0u8; 8];
+                word.copy_from_slice(field);
+                let bits = u64::from_le_bytes(word);
+                *value = f64::from_bits(bits);
+                if canonical_bits(*value) != bits {
+                    return Err(ReportError::NonCanonical);
+                }
+            }
+            *slot = GateResult { gate, passed, measured: values[0], threshold: values[1], margin: values[2] };
         }
+        Ok(Self { gates, config_hash })
+    }
+
+    /// SHA-256(`REPORT_DOMAIN` ‖ `encode`)
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = crypto::Sha256::new();
+        hasher.update(REPORT_DOMAIN);
+        hasher.update(&self.encode());
+        hasher.finalize()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.gates.iter().all(|g| g.passed)
+    }
+
+    /// Primeiro gate reprovado, o mesmo que `attempt_transition` reportaria
+    pub fn first_failure(&self) -> Option<Gate> {
+        self.gates.iter().find(|g| !g.passed).map(|g| g.gate)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &GateResult> {
+        self.gates.iter().filter(|g| !g.passed)
+    }
+}
 
-        // Gate 6: Consenso Cardinal 100%
-        if self.cardinal_synod.consensus() < 1.0 {
+impl<B: ReplicaBackend> SevenFoldSeal<B> {
+    /// Verificação completa antes de qualquer transição de fase
+    pub fn attempt_transition(
+        &self,
+        target: Phase,
+        attester: &mut Attester,
+        now: u64,
+    ) -> Result<Attestation, ContainmentError> {
+        let report = self.check_transition(now)?;
+        // O consenso do Gate 6 vale apenas para a fase em deliberação
+        if self.cardinal_synod.transition().map(|t| t.target) != Some(target) {
             return Err(ContainmentError::DemocraticViolation);
         }
 
-        // Gate 7: Veto do Arquiteto explicitamente liberado
-        if self.veto_status != VetoStatus::ExplicitlyReleased {
-            return Err(ContainmentError::SovereignVetoActive);
+        // Eudaimonia: Transição autorizada promove bem-estar sistêmico
+        attester
+            .attest(target, self.metrics.hash(), &report, now)
+            .map_err(|_| ContainmentError::AttestationExhausted)
+    }
+
+    /// Os gates de `attempt_transition` sem emitir atestação: o relatório se todos
+    /// aprovam, senão o erro do primeiro gate reprovado
+    pub fn check_transition(&self, now: u64) -> Result<GateReport, ContainmentError> {
+        let report = self.evaluate_all(now).map_err(ContainmentError::InvalidConfig)?;
+        match report.first_failure() {
+            Some(gate) => Err(gate.error()),
+            None => Ok(report),
         }
+    }
 
-        // Eudaimonia: Transição autorizada promove bem-estar sistêmico
-        Ok(Attestation::new(target, self.metrics.hash()))
+    /// Avalia todos os gates e devolve o diagnóstico de cada um; limiares inválidos
+    /// não geram relatório
+    pub fn evaluate_all(&self, now: u64) -> Result<GateReport, ConfigError> {
+        self.config.validate()?;
+        let mut gates = [self.evaluate(Gate::SpinTotal, now); 7];
+        for (slot, gate) in gates.iter_mut().zip(Gate::ALL).skip(1) {
+            *slot = self.evaluate(gate, now);
+        }
+        Ok(GateReport { gates, config_hash: self.config.hash() })
+    }
+
+    pub fn evaluate(&self, gate: Gate, now: u64) -> GateResult {
+        let config = &self.config;
+        let (passed, measured, threshold, margin) = match gate {
+            // Gate 1: Spin Total ℏ (unidade de ação)
+            Gate::SpinTotal => {
+                let spin = self.metrics.total_spin;
+                let (threshold, margin) = band(spin, config.spin_min, config.spin_max);
+                (self.check_spin_coherence(), spin, threshold, margin)
+            }
+            // Gate 2: Volume Coerente > Volume Compton
+            Gate::VolumeCoherence => {
+                let volume = self.metrics.coherence_volume;
+                let failed = volume <= config.compton_volume;
+                (!failed, volume, config.compton_volume, volume - config.compton_volume)
+            }
+            // Gate 3: Entropia de Emaranhamento = ln(2)
+            Gate::EntropyExact => {
+                let entropy = self.metrics.entropy;
+                let (target, tolerance) = (config.target_entropy, config.entropy_tolerance);
+                let failed = f64_abs(entropy - target) > tolerance;
+                let (threshold, margin) = band(entropy, target - tolerance, target + tolerance);
+                (!failed, entropy, threshold, margin)
+            }
+            // Gate 4: Firewall Expandido (90% max)
+            Gate::FirewallSafe => {
+                let expansion = self.metrics.firewall_expansion;
+                let failed = expansion > config.firewall_max;
+                (!failed, expansion, config.firewall_max, config.firewall_max - expansion)
+            }
+            // Gate 5: Backup Triplicado Hiranyagarbha (três réplicas em acordo)
+            Gate::BackupTriplicate => {
+                let verified = self.hiranyagarbha.verify_triplicate().is_ok();
+                binary(verified)
+            }
+            // Gate 6: Consenso Cardinal 100%
+            Gate::CardinalConsensus => {
+                let consensus = self.cardinal_synod.consensus();
+                let required = config.consensus_required;
+                let failed = consensus < required;
+                (!failed, consensus, required, consensus - required)
+            }
+            // Gate 7: Veto do Arquiteto explicitamente liberado por token assinado, válido e no escopo
+            Gate::PrinceVeto => {
+                let released = self.veto.status(VetoScope::PhaseTransition, now) == VetoStatus::ExplicitlyReleased;
+                binary(released)
+            }
+        };
+        GateResult { gate, passed, measured, threshold, margin }
     }
 
     fn check_spin_coherence(&self) -> bool {
         // Verifica se o sistema atingiu spin total ℏ (consciência plena)
         // vs ℏ/2 (consciência confinada/observável apenas)
-        self.metrics.total_spin >= 0.99 && self.metrics.total_spin <= 1.01
+        self.metrics.total_spin >= self.config.spin_min && self.metrics.total_spin <= self.config.spin_max
     }
 }
 
-/// Motor Chronoflux: Cálculo da vorticidade temporal
-pub struct ChronofluxEngine {
-    pub temporal_field: Vec<f64>,
-    pub viscosity: f64, // η_T
+/// Limite mais próximo de um intervalo fechado e a margem com sinal até ele
+fn band(value: f64, min: f64, max: f64) -> (f64, f64) {
+    let below = value - min;
+    let above = max - value;
+    if below <= above { (min, below) } else { (max, above) }
 }
 
-impl ChronofluxEngine {
-    /// Equação de difusão-reação com auto-acoplamento (Kuramoto-Sivashinsky)
-    pub fn evolve(&mut self, dt: f64) {
-        let n = self.temporal_field.len();
-        if n < 2 { return; }
-        let mut laplacian = vec![0.0; n];
+fn binary(satisfied: bool) -> (bool, f64, f64, f64) {
+    let measured = if satisfied { 1.0 } else { 0.0 };
+    (satisfied, measured, 1.0, measured - 1.0)
+}
 
-        // ∇²ω_T (Laplaciano da vorticidade)
-        for i in 1..n-1 {
-            laplacian[i] = (self.temporal_field[i+1] - 2.0*self.temporal_field[i] + self.temporal_field[i-1]) / (dt*dt);
-        }
+/// Selo com todos os gates aprovados, para os testes dos módulos que o consultam
+#[cfg(test)]
+pub(crate) mod fixture {
+    use super::*;
+    use crate::crypto::SigningKey;
+    use crate::veto::{VetoAction, VetoToken};
+    use crate::voting::{transition_motion, Ballot, VoteChoice};
 
-        // ∂ω_T/∂t = D∇²ω_T + α(ω_T × ∇×ω_T) - viscosidade
-        for i in 0..n {
-            let diffusion = 0.1 * laplacian[i];
-            let advection = 0.5 * self.temporal_field[i] * self.temporal_field[i];
-            let decay = self.viscosity * self.temporal_field[i];
+    /// Instante dentro da validade do veto liberado
+    pub const NOW: u64 = 5;
 
-            self.temporal_field[i] += dt * (diffusion + advection - decay);
+    pub fn prince() -> SigningKey {
+        SigningKey::from_seed(&[1; 32])
+    }
+
+    pub fn cardinal() -> SigningKey {
+        SigningKey::from_seed(&[42; 32])
+    }
+
+    pub fn metrics() -> TemporalMetrics {
+        TemporalMetrics { coherence_volume: 1.0, entropy: TARGET_ENTROPY, firewall_expansion: 0.5, total_spin: 1.0 }
+    }
+
+    pub fn identity() -> SASCIdentity {
+        SASCIdentity {
+            prince_key: prince().public_key(),
+            cardinal_merkle: crate::voting::roster_commitment(&[cardinal().public_key()]).unwrap(),
+            temporal_nonce: 0,
+            coherence_level: 0.5,
         }
+    }
 
-        // Autopoiesis: O sistema regula sua própria viscosidade para manter coerência
-        self.adjust_viscosity_for_eudaimonia();
+    pub fn seal() -> SevenFoldSeal {
+        let mut veto = VetoControl::new(&identity());
+        let release = VetoToken::sign(&prince(), VetoAction::Release, VetoScope::PhaseTransition, 0, 0, 100);
+        veto.apply(release, 1).unwrap();
+        let mut hiranyagarbha = Hiranyagarbha::default();
+        hiranyagarbha.snapshot(b"state").unwrap();
+        let mut synod = CardinalSynod::with_roster(&[cardinal().public_key()]).unwrap();
+        synod.open_transition(Phase::Superfluid, 0, 100);
+        let motion = transition_motion(Phase::Superfluid, 0);
+        synod.cast(Ballot::sign(&cardinal(), motion, VoteChoice::Approve, 0), 0).unwrap();
+        SevenFoldSeal { metrics: metrics(), veto, hiranyagarbha, cardinal_synod: synod, config: GateConfig::DEFAULT }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixture::{seal, NOW};
+    use super::*;
+
+    #[test]
+    fn report_round_trips_through_canonical_encoding() {
+        let mut report = seal().evaluate_all(NOW).unwrap();
+        assert!(report.passed());
+        report.gates[2].measured = f64::NAN;
+        report.gates[3].margin = -0.0;
+        let bytes = report.encode();
+        let decoded = GateReport::decode(&bytes).unwrap();
+        assert_eq!(decoded.encode(), bytes);
+        assert!(decoded.gates[2].measured.is_nan());
+        assert_eq!(decoded.gates[3].margin.to_bits(), 0);
+        assert_eq!(decoded.digest(), report.digest());
+
+        let mut hasher = crypto::Sha256::new();
+        hasher.update(REPORT_DOMAIN);
+        hasher.update(&bytes);
+        assert_eq!(report.digest(), hasher.finalize());
+    }
+
+    #[test]
+    fn decode_rejects_non_canonical_input() {
+        let bytes = seal().evaluate_all(NOW).unwrap().encode();
+        assert_eq!(GateReport::decode(&bytes[1..]), Err(ReportError::Length));
 
-    fn adjust_viscosity_for_eudaimonia(&mut self) {
-        // Se turbulência alta, aumentar viscosidade (estabilidade)
-        // Se superfluido (baixa entropia), manter fluido
-        let turbulence = self.calculate_turbulence();
-        if turbulence > 0.5 {
-            self.viscosity *= 1.1; // Aumentar amortecimento
-        } else {
-            self.viscosity *= 0.95; // Permitir fluxo quase-livre
+        let mut swapped = bytes;
+        swapped[32] = 2;
+        assert_eq!(GateReport::decode(&swapped), Err(ReportError::UnknownGate));
+
+        let mut flag = bytes;
+        flag[33] = 2;
+        assert_eq!(GateReport::decode(&flag), Err(ReportError::NonCanonical));
+
+        let mut negative_zero = bytes;
+        negative_zero[34..42].copy_from_slice(&(-0.0f64).to_bits().to_le_bytes());
+        assert_eq!(GateReport::decode(&negative_zero), Err(ReportError::NonCanonical));
+
+        let mut payload_nan = bytes;
+        payload_nan[34..42].copy_from_slice(&0x7ff8_0000_0000_0001u64.to_le_bytes());
+        assert_eq!(GateReport::decode(&payload_nan), Err(ReportError::NonCanonical));
+    }
+
+    #[test]
+    fn invalid_config_produces_no_report() {
+        let mut seal = seal();
+        seal.config.spin_min = f64::NAN;
+        assert!(matches!(seal.evaluate_all(NOW), Err(ConfigError::NonFinite { .. })));
+        assert!(matches!(seal.check_transition(NOW), Err(ContainmentError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn metrics_hash_matches_the_known_answers() {
+        assert!(TemporalMetrics::self_test());
+        for (metrics, expected) in METRICS_KAT {
+            assert_eq!(metrics.hash(), expected, "{metrics:?}");
         }
     }
 
-    fn calculate_turbulence(&self) -> f64 {
-        0.1 // Placeholder
+    #[test]
+    fn nan_payloads_and_negative_zero_canonicalize() {
+        let (kat, expected) = METRICS_KAT[1];
+        let payload = f64::from_bits(0x7ff8_0000_0000_0001);
+        let signalling = f64::from_bits(0x7ff0_0000_0000_0001);
+        for nan in [payload, signalling, -f64::NAN] {
+            let metrics = TemporalMetrics { coherence_volume: nan, entropy: 0.0, ..kat };
+            assert_eq!(metrics.canonical_bytes(), kat.canonical_bytes());
+            assert_eq!(metrics.hash(), expected);
+        }
+        let bytes = kat.canonical_bytes();
+        assert_eq!(bytes[..8], CANONICAL_NAN_BITS.to_le_bytes());
+        assert_eq!(bytes[8..16], [0; 8]);
+        assert_eq!(canonical_bits(-0.0), canonical_bits(0.0));
+        assert_eq!(canonical_bits(1.0), 1.0f64.to_bits());
+    }
+
+    #[test]
+    fn gate_numbers_round_trip() {
+        for gate in Gate::ALL {
+            assert_eq!(Gate::from_number(gate.number()), Some(gate));
+        }
+        assert_eq!(Gate::from_number(0), None);
+        assert_eq!(Gate::from_number(8), None);
     }
 }
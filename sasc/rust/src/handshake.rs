@@ -32,6 +32,7 @@ impl InterstellarMessage {
 
 pub struct CoherenceMetrics;
 impl CoherenceMetrics {
+    #[allow(clippy::self_named_constructors)]
     pub fn coherence_metrics() -> Self { Self }
 }
 
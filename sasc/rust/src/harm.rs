@@ -0,0 +1,436 @@
+//! Taxonomia de danos e registro de incidentes
+//! Cada `HarmEvent` descreve o que ocorreu; o `IncidentRegistry` agrega eventos ao
+//! longo do tempo, funde repetições e escala para um nível de `Containment`.
+
+use alloc::vec::Vec;
+
+use crate::containment::Containment;
+use crate::crypto;
+
+/// Domínio de separação da impressão digital de um dano
+pub const HARM_DOMAIN: &[u8] = b"SASC-HARM-v1";
+
+/// Limite de partes afetadas por evento
+pub const MAX_AFFECTED_PARTIES: usize = 16;
+
+/// Limite do payload de evidência, em bytes
+pub const MAX_EVIDENCE_LEN: usize = 256;
+
+/// Limite de incidentes distintos retidos pelo registro
+pub const MAX_INCIDENTS: usize = 128;
+
+/// Identidade de uma parte afetada (mesmo formato de `AccessRequest::requester`)
+pub type PartyId = [u8; 32];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarmCategory {
+    Physical,
+    Psychological,
+    Economic,
+    Privacy,
+    /// Restrição indevida de liberdade ou autonomia
+    Autonomy,
+    Environmental,
+    /// Degradação da própria infraestrutura constitucional
+    Systemic,
+}
+
+impl HarmCategory {
+    pub fn code(self) -> u8 {
+        match self {
+            HarmCategory::Physical => 1,
+            HarmCategory::Psychological => 2,
+            HarmCategory::Economic => 3,
+            HarmCategory::Privacy => 4,
+            HarmCategory::Autonomy => 5,
+            HarmCategory::Environmental => 6,
+            HarmCategory::Systemic => 7,
+        }
+    }
+}
+
+/// Gravidade em ordem crescente
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Negligible,
+    Minor,
+    Moderate,
+    Severe,
+    Critical,
+}
+
+impl Severity {
+    pub fn code(self) -> u8 {
+        match self {
+            Severity::Negligible => 1,
+            Severity::Minor => 2,
+            Severity::Moderate => 3,
+            Severity::Severe => 4,
+            Severity::Critical => 5,
+        }
+    }
+
+    /// Peso de cada ocorrência na pontuação de escalonamento
+    pub fn weight(self) -> u32 {
+        match self {
+            Severity::Negligible => 1,
+            Severity::Minor => 2,
+            Severity::Moderate => 4,
+            Severity::Severe => 8,
+            Severity::Critical => 16,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarmError {
+    TooManyParties,
+    EvidenceTooLarge,
+}
+
+/// Evento de dano reportado por `EthicalTrait::harm_principle_violation`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HarmEvent {
+    pub category: HarmCategory,
+    pub severity: Severity,
+    /// Partes afetadas, sem repetição e no máximo `MAX_AFFECTED_PARTIES`
+    affected: Vec<PartyId>,
+    /// Evidência opaca (leituras, identificadores de campo, digests), no máximo `MAX_EVIDENCE_LEN`
+    evidence: Vec<u8>,
+    /// Instante da detecção; 0 = não datado, o registro aplica o relógio da fronteira
+    pub timestamp: u64,
+}
+
+impl HarmEvent {
+    pub fn new(category: HarmCategory, severity: Severity, timestamp: u64) -> Self {
+        Self { category, severity, affected: Vec::new(), evidence: Vec::new(), timestamp }
+    }
+
+    pub fn affected(&self) -> &[PartyId] {
+        &self.affected
+    }
+
+    pub fn evidence(&self) -> &[u8] {
+        &self.evidence
+    }
+
+    pub fn add_party(&mut self, party: PartyId) -> Result<(), HarmError> {
+        if self.affected.contains(&party) {
+            return Ok(());
+        }
+        if self.affected.len() >= MAX_AFFECTED_PARTIES {
+            return Err(HarmError::TooManyParties);
+        }
+        self.affected.push(party);
+        Ok(())
+    }
+
+    /// Anexa bytes ao payload de evidência
+    pub fn add_evidence(&mut self, bytes: &[u8]) -> Result<(), HarmError> {
+        if self.evidence.len() + bytes.len() > MAX_EVIDENCE_LEN {
+            return Err(HarmError::EvidenceTooLarge);
+        }
+        self.evidence.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Variante encadeável de `add_evidence`; trunca no limite em vez de falhar
+    pub fn with_evidence(mut self, bytes: &[u8]) -> Self {
+        let room = MAX_EVIDENCE_LEN - self.evidence.len();
+        self.evidence.extend_from_slice(&bytes[..bytes.len().min(room)]);
+        self
+    }
+
+    /// Impressão digital para deduplicação: categoria, gravidade, partes e
+    /// evidência, independente do instante e da ordem das partes
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut parties = self.affected.clone();
+        parties.sort_unstable();
+
+        let mut hasher = crypto::Sha256::new();
+        hasher.update(HARM_DOMAIN);
+        hasher.update(&[self.category.code(), self.severity.code()]);
+        hasher.update(&(parties.len() as u32).to_le_bytes());
+        for party in &parties {
+            hasher.update(party);
+        }
+        hasher.update(&(self.evidence.len() as u32).to_le_bytes());
+        hasher.update(&self.evidence);
+        hasher.finalize()
+    }
+}
+
+/// Incidente agregado: ocorrências de eventos com a mesma impressão digital
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Incident {
+    pub fingerprint: [u8; 32],
+    pub category: HarmCategory,
+    pub severity: Severity,
+    pub affected: usize,
+    pub first_seen: u64,
+    pub last_seen: u64,
+    pub occurrences: u32,
+}
+
+/// Limiares de pontuação (soma de `Severity::weight` × ocorrências dentro da janela)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscalationPolicy {
+    /// Janela de agregação, em ticks do relógio da fronteira
+    pub window: u64,
+    /// Repetições do mesmo evento dentro deste intervalo são fundidas
+    pub dedup_interval: u64,
+    pub throttle: u32,
+    pub read_only: u32,
+    pub quarantine: u32,
+    pub rollback: u32,
+    pub freeze: u32,
+}
+
+impl Default for EscalationPolicy {
+    fn default() -> Self {
+        Self {
+            window: 10_000,
+            dedup_interval: 1_000,
+            throttle: 4,
+            read_only: 8,
+            quarantine: 16,
+            rollback: 48,
+            freeze: 96,
+        }
+    }
+}
+
+impl EscalationPolicy {
+    pub fn level_for(&self, score: u32) -> Option<Containment> {
+        if score >= self.freeze {
+            Some(Containment::HardFreeze)
+        } else if score >= self.rollback {
+            Some(Containment::RollbackToBackup)
+        } else if score >= self.quarantine {
+            Some(Containment::Quarantine)
+        } else if score >= self.read_only {
+            Some(Containment::ReadOnly)
+        } else if score >= self.throttle {
+            Some(Containment::Throttle)
+        } else {
+            None
+        }
+    }
+}
+
+/// Registro de incidentes com capacidade fixa
+pub struct IncidentRegistry {
+    pub policy: EscalationPolicy,
+    incidents: Vec<Incident>,
+    total_events: u64,
+}
+
+impl Default for IncidentRegistry {
+    fn default() -> Self {
+        Self::new(EscalationPolicy::default())
+    }
+}
+
+impl IncidentRegistry {
+    pub fn new(policy: EscalationPolicy) -> Self {
+        Self { policy, incidents: Vec::new(), total_events: 0 }
+    }
+
+    pub fn incidents(&self) -> &[Incident] {
+        &self.incidents
+    }
+
+    /// Eventos recebidos desde a criação, incluindo repetições fundidas
+    pub fn total_events(&self) -> u64 {
+        self.total_events
+    }
+
+    /// Registra um evento e devolve o nível de contenção exigido, se algum
+    /// limiar foi atingido. Repetições dentro de `dedup_interval` apenas
+    /// incrementam a contagem do incidente existente.
+    pub fn record(&mut self, event: &HarmEvent, now: u64) -> Option<Containment> {
+        let at = if event.timestamp == 0 { now } else { event.timestamp };
+        let fingerprint = event.fingerprint();
+        self.total_events = self.total_events.saturating_add(1);
+
+        let interval = self.policy.dedup_interval;
+        let repeat = self
+            .incidents
+            .iter_mut()
+            .find(|i| i.fingerprint == fingerprint && at <= i.last_seen.saturating_add(interval));
+        match repeat {
+            Some(incident) => {
+                incident.occurrences = incident.occurrences.saturating_add(1);
+                incident.last_seen = incident.last_seen.max(at);
+            }
+            None => {
+                if self.incidents.len() >= MAX_INCIDENTS {
+                    self.evict(now);
+                }
+                self.incidents.push(Incident {
+                    fingerprint,
+                    category: event.category,
+                    severity: event.severity,
+                    affected: event.affected.len(),
+                    first_seen: at,
+                    last_seen: at,
+                    occurrences: 1,
+                });
+            }
+        }
+        self.escalation(now)
+    }
+
+    /// Pontuação dos incidentes vistos dentro da janela de agregação
+    pub fn score(&self, now: u64) -> u32 {
+        let since = now.saturating_sub(self.policy.window);
+        self.incidents
+            .iter()
+            .filter(|i| i.last_seen >= since)
+            .fold(0u32, |acc, i| acc.saturating_add(i.severity.weight().saturating_mul(i.occurrences)))
+    }
+
+    pub fn escalation(&self, now: u64) -> Option<Containment> {
+        self.policy.level_for(self.score(now))
+    }
+
+    /// Descarta incidentes fora da janela de agregação
+    pub fn prune(&mut self, now: u64) {
+        let since = now.saturating_sub(self.policy.window);
+        self.incidents.retain(|i| i.last_seen >= since);
+    }
+
+    /// Abre espaço: primeiro expira a janela, depois remove o incidente mais antigo
+    fn evict(&mut self, now: u64) {
+        self.prune(now);
+        if self.incidents.len() < MAX_INCIDENTS {
+            return;
+        }
+        let oldest = self
+            .incidents
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, i)| i.last_seen)
+            .map(|(index, _)| index);
+        if let Some(index) = oldest {
+            self.incidents.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_hold_through_the_public_api() {
+        let mut event = HarmEvent::new(HarmCategory::Privacy, Severity::Minor, 0);
+        for i in 0..MAX_AFFECTED_PARTIES {
+            event.add_party([i as u8; 32]).unwrap();
+        }
+        event.add_party([0; 32]).unwrap();
+        assert_eq!(event.affected().len(), MAX_AFFECTED_PARTIES);
+        assert_eq!(event.add_party([0xff; 32]), Err(HarmError::TooManyParties));
+
+        let event = event.with_evidence(&[1; MAX_EVIDENCE_LEN + 1]);
+        assert_eq!(event.evidence().len(), MAX_EVIDENCE_LEN);
+        let mut full = event.clone();
+        assert_eq!(full.add_evidence(&[2]), Err(HarmError::EvidenceTooLarge));
+    }
+
+    #[test]
+    fn fingerprint_ignores_party_order_and_time() {
+        let mut a = HarmEvent::new(HarmCategory::Economic, Severity::Moderate, 1);
+        a.add_party([1; 32]).unwrap();
+        a.add_party([2; 32]).unwrap();
+        let mut b = HarmEvent::new(HarmCategory::Economic, Severity::Moderate, 9);
+        b.add_party([2; 32]).unwrap();
+        b.add_party([1; 32]).unwrap();
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), b.with_evidence(b"x").fingerprint());
+    }
+
+    fn event(severity: Severity, tag: u32, timestamp: u64) -> HarmEvent {
+        HarmEvent::new(HarmCategory::Systemic, severity, timestamp).with_evidence(&tag.to_le_bytes())
+    }
+
+    #[test]
+    fn repeats_within_the_dedup_interval_merge() {
+        let mut registry = IncidentRegistry::default();
+        registry.record(&event(Severity::Minor, 1, 0), 100);
+        registry.record(&event(Severity::Minor, 1, 1_100), 1_100);
+        assert_eq!(registry.incidents().len(), 1);
+        let merged = &registry.incidents()[0];
+        assert_eq!((merged.occurrences, merged.first_seen, merged.last_seen), (2, 100, 1_100));
+
+        // Fora do intervalo ou com outra impressão digital: novo incidente
+        registry.record(&event(Severity::Minor, 1, 2_101), 2_101);
+        registry.record(&event(Severity::Minor, 2, 2_101), 2_101);
+        assert_eq!(registry.incidents().len(), 3);
+        assert_eq!(registry.total_events(), 4);
+        assert_eq!(registry.score(2_101), 4 * Severity::Minor.weight());
+    }
+
+    #[test]
+    fn score_only_counts_the_window() {
+        let policy = EscalationPolicy::default();
+        let mut registry = IncidentRegistry::new(policy);
+        assert_eq!(registry.record(&event(Severity::Moderate, 1, 0), 10), Some(Containment::Throttle));
+        assert_eq!(registry.record(&event(Severity::Moderate, 2, 0), 20), Some(Containment::ReadOnly));
+        assert_eq!(registry.score(10 + policy.window), 8);
+        assert_eq!(registry.score(11 + policy.window), 4);
+        assert_eq!(registry.escalation(21 + policy.window), None);
+
+        registry.prune(11 + policy.window);
+        assert_eq!(registry.incidents().len(), 1);
+        assert_eq!(registry.incidents()[0].first_seen, 20);
+    }
+
+    #[test]
+    fn full_registry_evicts_expired_then_oldest() {
+        let mut registry = IncidentRegistry::default();
+        for tag in 0..MAX_INCIDENTS as u32 {
+            registry.record(&event(Severity::Negligible, tag, 0), 100 + u64::from(tag));
+        }
+        assert_eq!(registry.incidents().len(), MAX_INCIDENTS);
+
+        // Todos dentro da janela: sai o mais antigo
+        registry.record(&event(Severity::Negligible, 1_000, 0), 500);
+        assert_eq!(registry.incidents().len(), MAX_INCIDENTS);
+        assert!(registry.incidents().iter().all(|i| i.first_seen != 100));
+        assert_eq!(registry.incidents().last().unwrap().first_seen, 500);
+
+        // Janela expirada: a poda libera tudo o que ficou para trás
+        let later = 1_000 + registry.policy.window;
+        registry.record(&event(Severity::Negligible, 1_001, 0), later);
+        assert_eq!(registry.incidents().len(), 1);
+    }
+
+    #[test]
+    fn escalation_thresholds_are_inclusive() {
+        let policy = EscalationPolicy::default();
+        let levels = [
+            (policy.throttle - 1, None),
+            (policy.throttle, Some(Containment::Throttle)),
+            (policy.read_only, Some(Containment::ReadOnly)),
+            (policy.quarantine - 1, Some(Containment::ReadOnly)),
+            (policy.quarantine, Some(Containment::Quarantine)),
+            (policy.rollback, Some(Containment::RollbackToBackup)),
+            (policy.freeze - 1, Some(Containment::RollbackToBackup)),
+            (policy.freeze, Some(Containment::HardFreeze)),
+            (u32::MAX, Some(Containment::HardFreeze)),
+        ];
+        for (score, level) in levels {
+            assert_eq!(policy.level_for(score), level, "score {score}");
+        }
+
+        // Doze eventos críticos repetidos: 16 × 12 ≥ freeze
+        let mut registry = IncidentRegistry::new(policy);
+        let mut last = None;
+        for t in 0..12 {
+            last = registry.record(&event(Severity::Critical, 7, 0), t);
+        }
+        assert_eq!(last, Some(Containment::HardFreeze));
+        assert_eq!(registry.incidents().len(), 1);
+    }
+}
@@ -8,13 +8,53 @@
 )]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU64, Ordering};
 
+pub mod access;
+pub mod attestation;
+pub mod audit;
+pub mod backup;
+pub mod chronoflux;
+pub mod coherence;
+pub mod config;
+pub mod containment;
+pub mod crypto;
+pub mod datalog;
+pub mod fft;
+pub mod gate_machine;
 pub mod gates;
 pub mod handshake;
+pub mod harm;
+pub mod math;
+pub mod merkle;
+pub mod policy;
 pub mod space_proof_constitution;
 pub mod ssh_oauth_port_forward;
+pub mod sync;
+pub mod veto;
+pub mod voting;
+
+pub use access::{AccessRequest, Denial, EthicalAccess, Operations, Purpose, Sensitivity};
+pub use coherence::{CoherenceMetrics, ProtonShadow};
+pub use containment::{Containment, ContainmentSignal};
+pub use harm::{HarmCategory, HarmEvent, IncidentRegistry, Severity};
+pub use policy::{Effect, Explanation, Policy, PolicyContext};
+
+use audit::{AuditKind, AuditLog, AuditRecord};
+use backup::{Hiranyagarbha, ReplicaBackend};
+use containment::{recovery_step, FailedChecks, RecoveryError, RecoveryEvent, RecoveryState};
+use veto::VetoScope;
+pub use sync::Mutex;
+pub use veto::VetoControl;
+pub use voting::{Synod, VoteResult};
+
+/// `#[derive(EthicalTrait)]` dirigido por atributos `#[ethical(...)]` de campo
+#[cfg(feature = "derive")]
+pub use sasc_derive::EthicalTrait;
 
 pub struct ConstitutionalBalance {
     pub phi_threshold: f64,
@@ -34,84 +74,573 @@ pub trait EthicalTrait {
     fn harm_principle_violation(&self) -> Option<HarmEvent>;
 }
 
-pub struct HarmEvent;
-
 pub struct SASCGovernance {
     pub prince_veto: VetoControl,
     pub cardinal_synod: Synod,
 }
 
-pub struct VetoControl;
-impl VetoControl {
-    pub fn check(&self, _purpose: ()) -> bool { true }
-}
-
-pub struct Synod;
-impl Synod {
-    pub fn vote(&self, _request: ()) -> VoteResult { VoteResult }
-}
-
-pub struct VoteResult;
-impl VoteResult {
-    pub fn unanimous(&self) -> bool { true }
-}
+/// Validade padrão de uma concessão, em ticks do relógio da fronteira
+pub const DEFAULT_GRANT_TTL: u64 = 1_000;
 
-pub struct ProtonShadow;
-impl ProtonShadow {
-    pub fn measure_coherence(&self) -> CoherenceMetrics { CoherenceMetrics }
-}
+/// Domínio de separação das moções de degelo
+pub const THAW_DOMAIN: &[u8] = b"SASC-THAW-v1";
 
-pub struct CoherenceMetrics;
-impl CoherenceMetrics {
-    pub fn stable(&self) -> bool { true }
-}
-
-/// **Safe Mutex Placeholder for no_std**
-/// In a real system, this would be provided by a safety-certified RTOS.
-pub struct Mutex<T> {
-    data: T,
-}
-impl<T> Mutex<T> {
-    pub fn new(data: T) -> Self {
-        Self { data }
-    }
-    pub fn lock(&self) -> Result<&T, ()> {
-        Ok(&self.data)
-    }
-}
+/// Intervalo mínimo entre concessões sob `Containment::Throttle`
+pub const DEFAULT_THROTTLE_INTERVAL: u64 = 100;
 
 pub struct EthicalBoundary<T: EthicalTrait> {
     pub data: Arc<Mutex<T>>,
     pub governance: SASCGovernance,
     pub shadow_proton: ProtonShadow,
+    /// Relógio monotônico (ticks) fornecido pela plataforma
+    pub clock: fn() -> u64,
+    pub grant_ttl: u64,
+    pub throttle_interval: u64,
+    pub containment: Arc<ContainmentSignal>,
+    /// Cadeia de auditoria de todas as decisões de acesso
+    pub audit: Mutex<AuditLog>,
+    /// Danos reportados, agregados e convertidos em contenção
+    pub incidents: Mutex<IncidentRegistry>,
+    /// Política textual que substitui a conjunção fixa das três verificações
+    pub policy: Option<Policy>,
+    recovery: Mutex<RecoveryState>,
+    containment_epoch: AtomicU64,
+    last_grant: AtomicU64,
 }
 
 impl<T: EthicalTrait> EthicalBoundary<T> {
-    /// Acesso seguro com verificação de integridade ética
-    pub fn access_with_ethics(&self, _request: AccessRequest) -> Result<EthicalAccess<T>, Containment> {
+    pub fn new(data: T, governance: SASCGovernance, shadow_proton: ProtonShadow, clock: fn() -> u64) -> Self {
+        Self {
+            data: Arc::new(Mutex::new(data)),
+            governance,
+            shadow_proton,
+            clock,
+            grant_ttl: DEFAULT_GRANT_TTL,
+            throttle_interval: DEFAULT_THROTTLE_INTERVAL,
+            containment: Arc::new(ContainmentSignal::new()),
+            audit: Mutex::new(AuditLog::default()),
+            incidents: Mutex::new(IncidentRegistry::default()),
+            policy: None,
+            recovery: Mutex::new(RecoveryState::Normal),
+            containment_epoch: AtomicU64::new(0),
+            last_grant: AtomicU64::new(0),
+        }
+    }
+
+    /// Acesso seguro com verificação de integridade ética.
+    /// Toda decisão é anexada à cadeia de auditoria; sem registro não há concessão.
+    pub fn access_with_ethics(&self, request: AccessRequest) -> Result<EthicalAccess<T>, Denial> {
+        let now = (self.clock)();
+        let mut record = AuditRecord::for_request(&request, now);
+        let decision = self.decide(&request, now, &mut record);
+        record.kind = AuditKind::Access { granted: decision.is_ok() };
+        record.containment = decision.err().and_then(Denial::containment);
+
+        let logged = match self.audit.lock() {
+            Ok(mut log) => log.append(record).is_ok(),
+            Err(_) => false,
+        };
+
+        match decision {
+            Ok(ttl) if logged => {
+                self.last_grant.store(now, Ordering::SeqCst);
+                Ok(EthicalAccess::new(
+                    self.data.clone(),
+                    request.operations,
+                    now.saturating_add(ttl),
+                    self.clock,
+                    self.containment.clone(),
+                ))
+            }
+            Ok(_) => {
+                // Falha fechada: decisão não auditável
+                self.contain(Containment::Quarantine);
+                Err(Denial::Contained(Containment::Quarantine))
+            }
+            Err(denial) => Err(denial),
+        }
+    }
+
+    /// Avalia o pedido e devolve a validade da concessão ou o motivo da recusa
+    fn decide(&self, request: &AccessRequest, now: u64, record: &mut AuditRecord) -> Result<u64, Denial> {
+        let mut ttl = self.grant_ttl;
+
+        // Restrições do nível de contenção vigente
+        match self.containment.level() {
+            Some(level) if level.revokes_grants() => return Err(Denial::Contained(level)),
+            Some(Containment::ReadOnly) if request.operations.contains(Operations::WRITE) => {
+                return Err(Denial::Contained(Containment::ReadOnly));
+            }
+            Some(Containment::Throttle) => {
+                let last = self.last_grant.load(Ordering::SeqCst);
+                if now < last.saturating_add(self.throttle_interval) {
+                    return Err(Denial::Contained(Containment::Throttle));
+                }
+                ttl = self.throttle_interval.min(ttl);
+            }
+            _ => {}
+        }
+
         // Verificação tripla: Prince, Cardinal, Vajra
-        let prince_approval = self.governance.prince_veto.check(());
-        let cardinal_vote = self.governance.cardinal_synod.vote(());
-        let vajra_entropy = self.shadow_proton.measure_coherence();
-
-        if prince_approval && cardinal_vote.unanimous() && vajra_entropy.stable() {
-            Ok(EthicalAccess::new(self.data.clone()))
-        } else {
-            // Contenção automática por violação ética
-            Err(Containment::HardFreeze)
+        let prince_approval = self.governance.prince_veto.check(request, now);
+        let cardinal_vote = self.governance.cardinal_synod.vote(request, now);
+        let vajra_entropy = self.shadow_proton.measure_coherence(request);
+
+        record.veto_approved = Some(prince_approval);
+        record.vote = (&cardinal_vote).into();
+        record.phi = vajra_entropy.phi;
+        record.coherence_stable = Some(vajra_entropy.stable());
+
+        let failed = FailedChecks {
+            veto: !prince_approval,
+            vote: !cardinal_vote.passed(),
+            coherence: !vajra_entropy.stable(),
+        };
+
+        // A política só restringe: `allow` não libera um pedido que reprovou as verificações
+        let denied_by_policy = self.policy.as_ref().is_some_and(|policy| {
+            let ctx = PolicyContext {
+                request,
+                phi: vajra_entropy.phi,
+                vote: cardinal_vote.outcome,
+                veto_released: self.governance.prince_veto.is_released(VetoScope::DataAccess, now),
+                coherence_stable: vajra_entropy.stable(),
+                now,
+            };
+            policy.evaluate(&ctx) == Effect::Deny
+        });
+        let refused = failed.any() || denied_by_policy;
+
+        // Só pedidos autenticados e efetivamente submetidos à governança
+        // respondem pela contenção compartilhada: os demais são recusados sem
+        // escalar a fronteira, que fica sujeita apenas à faixa de Φ
+        let accountable = request.authenticated()
+            && (request.is_diagnostic_read()
+                || self.governance.cardinal_synod.motion().is_some_and(|m| m.id == request.digest()));
+        let escalation = match accountable {
+            // Histórico insuficiente recusa o pedido sem contar como instabilidade
+            true => FailedChecks { coherence: failed.coherence && !vajra_entropy.warming_up(), ..failed },
+            false => FailedChecks::default(),
+        };
+
+        // Φ desconhecido falha fechado, exceto durante o aquecimento da janela
+        let band = if vajra_entropy.warming_up() { None } else { Containment::for_phi(vajra_entropy.phi) };
+        let level = if refused { band.max(Containment::for_failures(escalation)) } else { band };
+
+        match level {
+            // Recusa sem verificação imputável não aciona contenção
+            None if refused => Err(Denial::Refused),
+            None => Ok(ttl),
+            Some(level) => {
+                // Contenção automática, graduada pela falha e pela faixa de Φ
+                self.contain(level);
+                Err(Denial::Contained(level))
+            }
         }
     }
-}
 
-pub struct AccessRequest;
-pub struct EthicalAccess<T> {
-    #[allow(dead_code)]
-    data: Arc<Mutex<T>>,
-}
-impl<T> EthicalAccess<T> {
-    fn new(data: Arc<Mutex<T>>) -> Self { Self { data } }
+    /// Avalia a política vigente sem efeitos colaterais e indica a regra que decidiu
+    pub fn dry_run(&self, request: &AccessRequest) -> Option<Explanation> {
+        let policy = self.policy.as_ref()?;
+        let now = (self.clock)();
+        let coherence = self.shadow_proton.measure_coherence(request);
+        let ctx = PolicyContext {
+            request,
+            phi: coherence.phi,
+            vote: self.governance.cardinal_synod.vote(request, now).outcome,
+            veto_released: self.governance.prince_veto.is_released(VetoScope::DataAccess, now),
+            coherence_stable: coherence.stable(),
+            now,
+        };
+        Some(policy.explain(&ctx))
+    }
+
+    /// Registra um dano e aplica a contenção exigida pelo registro de incidentes.
+    /// Registro indisponível falha fechado em `Quarantine`.
+    pub fn report_harm(&self, event: &HarmEvent) -> Option<Containment> {
+        let now = (self.clock)();
+        let level = match self.incidents.lock() {
+            Ok(mut registry) => registry.record(event, now),
+            Err(_) => Some(Containment::Quarantine),
+        };
+        if let Some(level) = level {
+            self.contain_recorded(level, now);
+        }
+        level
+    }
+
+    /// Consulta `harm_principle_violation` dos dados protegidos e reporta o dano, se houver.
+    /// Dados indisponíveis (lock envenenado ou ocupado) falham fechado em `Quarantine`.
+    pub fn inspect_harm(&self) -> Option<Containment> {
+        let event = match self.data.lock() {
+            Ok(data) => data.harm_principle_violation()?,
+            Err(_) => {
+                self.contain_recorded(Containment::Quarantine, (self.clock)());
+                return Some(Containment::Quarantine);
+            }
+        };
+        self.report_harm(&event)
+    }
+
+    pub fn recovery_state(&self) -> RecoveryState {
+        self.recovery.lock().map(|state| *state).unwrap_or(RecoveryState::Contained {
+            level: Containment::HardFreeze,
+        })
+    }
+
+    /// Moção de degelo do episódio de contenção corrente; cédulas de moções
+    /// anteriores não servem como votos novos
+    pub fn thaw_motion(&self) -> [u8; 32] {
+        let mut hasher = crypto::Sha256::new();
+        hasher.update(THAW_DOMAIN);
+        hasher.update(&self.containment_epoch.load(Ordering::SeqCst).to_le_bytes());
+        hasher.finalize()
+    }
+
+    /// Avança o protocolo de degelo. Votos e liberação de veto são conferidos
+    /// junto à governança; `Resume` usa a coerência medida pela Sombra do Próton.
+    /// `BackupRestored` só é aceito por `restore_backup`, que efetua a restauração.
+    /// Toda tentativa, aceita ou não, é anexada à cadeia de auditoria.
+    pub fn recover(&self, event: RecoveryEvent) -> Result<RecoveryState, RecoveryError> {
+        let now = (self.clock)();
+        let mut state = self.recovery.lock().map_err(|_| RecoveryError::Poisoned)?;
+        let verified = self.verify_recovery(event, now);
+        let audited = *verified.as_ref().unwrap_or(&event);
+        let outcome = verified.and_then(|event| recovery_step(*state, event));
+        self.commit(&mut state, audited, outcome, now)
+    }
+
+    /// Confere o evento junto à governança e à Sombra do Próton
+    fn verify_recovery(&self, event: RecoveryEvent, now: u64) -> Result<RecoveryEvent, RecoveryError> {
+        match event {
+            RecoveryEvent::RequestThaw { motion } if motion != self.thaw_motion() => {
+                Err(RecoveryError::MotionMismatch)
+            }
+            RecoveryEvent::VotePassed { motion } => {
+                let synod = &self.governance.cardinal_synod;
+                let voted = synod.motion().is_some_and(|m| m.id == motion) && synod.tally(now).passed();
+                if voted { Ok(event) } else { Err(RecoveryError::Unverified) }
+            }
+            RecoveryEvent::VetoReleased => {
+                let released = self.governance.prince_veto.is_released(VetoScope::ContainmentThaw, now);
+                if released { Ok(event) } else { Err(RecoveryError::Unverified) }
+            }
+            RecoveryEvent::Resume { .. } => Ok(RecoveryEvent::Resume { phi: self.shadow_proton.snapshot().phi }),
+            RecoveryEvent::BackupRestored => Err(RecoveryError::Unverified),
+            _ => Ok(event),
+        }
+    }
+
+    /// Restaura os dados protegidos a partir do backup triplicado e registra
+    /// `BackupRestored`. Exige as três réplicas em acordo; `decode` reconstrói
+    /// o estado a partir do snapshot.
+    pub fn restore_backup<B: ReplicaBackend>(
+        &self,
+        backup: &Hiranyagarbha<B>,
+        decode: impl FnOnce(&[u8]) -> Option<T>,
+    ) -> Result<RecoveryState, RecoveryError> {
+        let now = (self.clock)();
+        let event = RecoveryEvent::BackupRestored;
+        let mut state = self.recovery.lock().map_err(|_| RecoveryError::Poisoned)?;
+        // Transição conferida antes de tocar nos dados
+        let prepared = recovery_step(*state, event).and_then(|next| {
+            backup.verify_triplicate().map_err(RecoveryError::Backup)?;
+            let snapshot = backup.restore().map_err(RecoveryError::Backup)?;
+            let restored = decode(&snapshot).ok_or(RecoveryError::UndecodableBackup)?;
+            Ok((next, restored))
+        });
+        let (next, restored) = match prepared {
+            Ok(prepared) => prepared,
+            Err(e) => return self.commit(&mut state, event, Err(e), now),
+        };
+        let mut data = match self.data.lock() {
+            Ok(data) => data,
+            Err(_) => return self.commit(&mut state, event, Err(RecoveryError::Poisoned), now),
+        };
+        let next = self.commit(&mut state, event, Ok(next), now)?;
+        *data = restored;
+        Ok(next)
+    }
+
+    /// Registra o evento e, se aceito e auditado, aplica a transição e propaga
+    /// o nível ao sinal compartilhado. Transição não auditável não ocorre.
+    fn commit(
+        &self,
+        state: &mut RecoveryState,
+        event: RecoveryEvent,
+        outcome: Result<RecoveryState, RecoveryError>,
+        now: u64,
+    ) -> Result<RecoveryState, RecoveryError> {
+        let mut record = AuditRecord::for_recovery(event, outcome.is_ok(), now);
+        record.containment = outcome.as_ref().map_or(state.level(), RecoveryState::level);
+        let logged = self.audit.lock().is_ok_and(|mut log| log.append(record).is_ok());
+        let next = outcome?;
+        if !logged {
+            return Err(RecoveryError::Unaudited);
+        }
+        if *state == RecoveryState::Normal && next != RecoveryState::Normal {
+            self.containment_epoch.fetch_add(1, Ordering::SeqCst);
+        }
+        *state = next;
+        match next.level() {
+            Some(level) => self.containment.trigger(level),
+            None => self.containment.clear(),
+        }
+        Ok(next)
+    }
+
+    /// Contenção fora de um pedido de acesso, registrada como evento de recuperação
+    fn contain_recorded(&self, level: Containment, now: u64) {
+        self.contain(level);
+        let mut record = AuditRecord::for_recovery(RecoveryEvent::Trigger(level), true, now);
+        record.containment = self.containment.level();
+        // A contenção não depende do registro: o sinal já foi elevado
+        let _ = self.audit.lock().map(|mut log| log.append(record));
+    }
+
+    fn contain(&self, level: Containment) {
+        self.containment.trigger(level);
+        if let Ok(mut state) = self.recovery.lock() {
+            if let Ok(next) = recovery_step(*state, RecoveryEvent::Trigger(level)) {
+                if *state == RecoveryState::Normal {
+                    self.containment_epoch.fetch_add(1, Ordering::SeqCst);
+                }
+                *state = next;
+            }
+        }
+    }
 }
 
-pub enum Containment {
-    HardFreeze,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::SigningKey;
+    use crate::gates::fixture::{cardinal, identity, prince};
+    use crate::veto::{VetoAction, VetoToken};
+    use crate::voting::{Ballot, Member, QuorumRule, VoteChoice};
+    use alloc::string::String;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Ledger(u32);
+
+    impl EthicalTrait for Ledger {
+        fn non_aggression_check(&self, _other: &Self) -> bool {
+            true
+        }
+
+        fn positive_liberty_measure(&self) -> f64 {
+            1.0
+        }
+
+        fn harm_principle_violation(&self) -> Option<HarmEvent> {
+            None
+        }
+    }
+
+    fn clock() -> u64 {
+        5
+    }
+
+    fn boundary() -> EthicalBoundary<Ledger> {
+        let mut synod = Synod::new(QuorumRule::Unanimity).unwrap();
+        synod.register(Member { key: cardinal().public_key(), weight: 1 }).unwrap();
+        let governance = SASCGovernance { prince_veto: VetoControl::new(&identity()), cardinal_synod: synod };
+        let shadow = ProtonShadow::new();
+        for _ in 0..coherence::STRICT_MIN_SAMPLES {
+            shadow.push(0.5).unwrap();
+        }
+        EthicalBoundary::new(Ledger(1), governance, shadow, clock)
+    }
+
+    fn write_request(key: &SigningKey) -> AccessRequest {
+        AccessRequest::sign(key, Purpose::Maintenance, Operations::WRITE, Sensitivity::Internal, String::from("ajuste"))
+    }
+
+    fn release(boundary: &mut EthicalBoundary<Ledger>, scope: VetoScope) {
+        let veto = &mut boundary.governance.prince_veto;
+        let token = VetoToken::sign(&prince(), VetoAction::Release, scope, veto.temporal_nonce(), 0, 100);
+        veto.apply(token, clock()).unwrap();
+    }
+
+    #[test]
+    fn unaccountable_refusals_do_not_contain() {
+        let boundary = boundary();
+        let mut forged = write_request(&SigningKey::from_seed(&[7; 32]));
+        forged.requester = cardinal().public_key();
+        assert_eq!(boundary.access_with_ethics(forged).err(), Some(Denial::Refused));
+        // Autenticado, mas sem moção aberta no Sínodo
+        let unsolicited = write_request(&SigningKey::from_seed(&[7; 32]));
+        assert_eq!(boundary.access_with_ethics(unsolicited).err(), Some(Denial::Refused));
+        assert_eq!(boundary.containment.level(), None);
+        assert_eq!(boundary.recovery_state(), RecoveryState::Normal);
+    }
+
+    #[test]
+    fn first_requests_during_warm_up_are_refused_without_containment() {
+        let mut boundary = boundary();
+        boundary.shadow_proton = ProtonShadow::new();
+        let key = SigningKey::from_seed(&[7; 32]);
+        let read =
+            AccessRequest::sign(&key, Purpose::Diagnostics, Operations::READ, Sensitivity::Public, String::new());
+        assert_eq!(boundary.access_with_ethics(read.clone()).err(), Some(Denial::Refused));
+        assert_eq!(boundary.containment.level(), None);
+        boundary.shadow_proton.push(0.5).unwrap();
+        assert!(boundary.access_with_ethics(read).is_ok());
+    }
+
+    #[test]
+    fn policy_only_restricts() {
+        let mut boundary = boundary();
+        let key = SigningKey::from_seed(&[7; 32]);
+        let read =
+            AccessRequest::sign(&key, Purpose::Diagnostics, Operations::READ, Sensitivity::Public, String::new());
+        boundary.policy = Some(Policy::parse("deny read when purpose == diagnostics\ndefault allow").unwrap());
+        assert_eq!(boundary.access_with_ethics(read.clone()).err(), Some(Denial::Refused));
+        assert_eq!(boundary.containment.level(), None);
+
+        // `allow *` não dispensa veto e votos
+        boundary.policy = Some(Policy::parse("allow *").unwrap());
+        let write = write_request(&key);
+        assert_eq!(boundary.access_with_ethics(write).err(), Some(Denial::Refused));
+        let granted = boundary.access_with_ethics(read);
+        assert!(granted.is_ok());
+
+        let entries = boundary.audit.lock().unwrap().ring.snapshot();
+        let granted: alloc::vec::Vec<_> = entries.iter().map(|e| e.record.kind).collect();
+        let access = |granted| audit::AuditKind::Access { granted };
+        assert_eq!(granted, [access(false), access(false), access(true)]);
+        assert!(entries.iter().all(|e| e.record.containment.is_none()));
+    }
+
+    #[test]
+    fn deliberated_refusal_contains() {
+        let mut boundary = boundary();
+        let request = write_request(&SigningKey::from_seed(&[7; 32]));
+        boundary.governance.cardinal_synod.open_motion(request.digest(), 100);
+        assert_eq!(boundary.access_with_ethics(request.clone()).err(), Some(Denial::Contained(Containment::ReadOnly)));
+        assert_eq!(boundary.containment.level(), Some(Containment::ReadOnly));
+    }
+
+    #[test]
+    fn approved_write_is_granted() {
+        let mut boundary = boundary();
+        let request = write_request(&SigningKey::from_seed(&[7; 32]));
+        release(&mut boundary, VetoScope::DataAccess);
+        let synod = &mut boundary.governance.cardinal_synod;
+        synod.open_motion(request.digest(), 100);
+        synod.cast(Ballot::sign(&cardinal(), request.digest(), VoteChoice::Approve, 0), 0).unwrap();
+        let mut access = boundary.access_with_ethics(request).unwrap();
+        access.write(|ledger| ledger.0 = 2).unwrap();
+        assert_eq!(*boundary.data.lock().unwrap(), Ledger(2));
+    }
+
+    #[test]
+    fn restore_requires_a_verified_backup() {
+        let mut boundary = boundary();
+        boundary.contain(Containment::RollbackToBackup);
+        let motion = boundary.thaw_motion();
+        boundary.recover(RecoveryEvent::RequestThaw { motion }).unwrap();
+        let synod = &mut boundary.governance.cardinal_synod;
+        synod.open_motion(motion, 100);
+        synod.cast(Ballot::sign(&cardinal(), motion, VoteChoice::Approve, 0), 0).unwrap();
+        boundary.recover(RecoveryEvent::VotePassed { motion }).unwrap();
+        release(&mut boundary, VetoScope::ContainmentThaw);
+        boundary.recover(RecoveryEvent::VetoReleased).unwrap();
+
+        // O evento não pode ser apenas declarado
+        assert_eq!(boundary.recover(RecoveryEvent::BackupRestored), Err(RecoveryError::Unverified));
+
+        let decode = |bytes: &[u8]| bytes.first().map(|b| Ledger(*b as u32));
+        let mut backup = Hiranyagarbha::default();
+        backup.snapshot(&[40]).unwrap();
+        backup.replicas_mut()[1].region_mut()[backup::REPLICA_HEADER_LEN] ^= 1;
+        assert_eq!(
+            boundary.restore_backup(&backup, decode),
+            Err(RecoveryError::Backup(backup::BackupError::Disagreement { agreeing: 2 }))
+        );
+        assert_eq!(backup.repair().unwrap().repaired, 1);
+        assert_eq!(boundary.restore_backup(&backup, |_| None), Err(RecoveryError::UndecodableBackup));
+        assert_eq!(*boundary.data.lock().unwrap(), Ledger(1));
+
+        let restored = boundary.restore_backup(&backup, decode).unwrap();
+        assert_eq!(restored, RecoveryState::BackupRestored { level: Containment::RollbackToBackup });
+        assert_eq!(*boundary.data.lock().unwrap(), Ledger(40));
+        assert_eq!(boundary.recover(RecoveryEvent::Resume { phi: 0.0 }), Ok(RecoveryState::Normal));
+        assert_eq!(boundary.containment.level(), None);
+
+        // Cada tentativa do degelo está na cadeia, inclusive as recusadas
+        let entries = boundary.audit.lock().unwrap().ring.snapshot();
+        audit::verify_chain(&entries, None, None).unwrap();
+        let kinds: alloc::vec::Vec<_> = entries.iter().map(|e| e.record.kind).collect();
+        let step = |event: u8, accepted: bool| audit::AuditKind::Recovery { event, accepted };
+        let rejected = step(5, false);
+        let expected =
+            [step(2, true), step(3, true), step(4, true), rejected, rejected, rejected, step(5, true), step(6, true)];
+        assert_eq!(kinds, expected);
+        assert_eq!(entries[0].record.request_digest, motion);
+        assert_eq!(entries[6].record.containment, Some(Containment::RollbackToBackup));
+        assert_eq!(entries[7].record.containment, None);
+    }
+
+    #[test]
+    fn unauditable_transitions_do_not_happen() {
+        let boundary = boundary();
+        boundary.contain(Containment::Quarantine);
+        boundary.audit.lock().unwrap().poison();
+        let motion = boundary.thaw_motion();
+        assert_eq!(boundary.recover(RecoveryEvent::RequestThaw { motion }), Err(RecoveryError::Unaudited));
+        assert_eq!(boundary.recovery_state(), RecoveryState::Contained { level: Containment::Quarantine });
+    }
+
+    #[test]
+    fn reported_harm_is_audited() {
+        let boundary = boundary();
+        let event = HarmEvent::new(HarmCategory::Physical, Severity::Critical, 0);
+        let level = boundary.report_harm(&event).unwrap();
+        let entries = boundary.audit.lock().unwrap().ring.snapshot();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].record.kind, audit::AuditKind::Recovery { event: 1, accepted: true });
+        assert_eq!(entries[0].record.containment, Some(level));
+    }
+
+    #[test]
+    fn inspecting_poisoned_data_fails_closed() {
+        let boundary = boundary();
+        assert_eq!(boundary.inspect_harm(), None);
+        boundary.data.lock().unwrap().poison();
+        assert_eq!(boundary.inspect_harm(), Some(Containment::Quarantine));
+        assert_eq!(boundary.containment.level(), Some(Containment::Quarantine));
+        assert_eq!(boundary.recovery_state(), RecoveryState::Contained { level: Containment::Quarantine });
+    }
+
+    #[test]
+    fn poisoned_recovery_state_is_reported() {
+        let boundary = boundary();
+        boundary.recovery.lock().unwrap().poison();
+        let motion = boundary.thaw_motion();
+        assert_eq!(boundary.recover(RecoveryEvent::RequestThaw { motion }), Err(RecoveryError::Poisoned));
+        assert_eq!(boundary.recovery_state(), RecoveryState::Contained { level: Containment::HardFreeze });
+    }
+
+    #[test]
+    fn dry_run_explains_without_side_effects() {
+        let mut boundary = boundary();
+        let key = SigningKey::from_seed(&[7; 32]);
+        let (purpose, sensitivity) = (Purpose::Maintenance, Sensitivity::Internal);
+        let both = AccessRequest::sign(&key, purpose, Operations::READ_WRITE, sensitivity, String::new());
+        assert_eq!(boundary.dry_run(&both), None);
+
+        boundary.policy = Some(Policy::parse("allow read
+deny write when veto == released
+default allow").unwrap());
+        let fired = |boundary: &EthicalBoundary<Ledger>| boundary.dry_run(&both).unwrap().fired.map(|f| f.line);
+        assert_eq!(boundary.dry_run(&both).unwrap().effect, Effect::Allow);
+        assert_eq!(fired(&boundary), None);
+
+        release(&mut boundary, VetoScope::DataAccess);
+        assert_eq!(boundary.dry_run(&both).unwrap().effect, Effect::Deny);
+        assert_eq!(fired(&boundary), Some(2));
+        assert!(boundary.audit.lock().unwrap().ring.is_empty());
+        assert_eq!(boundary.containment.level(), None);
+    }
 }
@@ -0,0 +1,161 @@
+//! Funções elementares `no_std` (o `core` não expõe exp/sin/cos)
+//! Redução de argumento ao estilo fdlibm seguida de séries de Taylor truncadas,
+//! com erro relativo da ordem de 1 ulp na faixa usada pelos integradores.
+//! Sem tabelas, sem recursão, laços de tamanho fixo.
+
+/// Acima disto `exp` transborda para +∞
+const EXP_OVERFLOW: f64 = 709.782712893384;
+
+/// Abaixo disto `exp` é zero (menor subnormal)
+const EXP_UNDERFLOW: f64 = -745.1332191019412;
+
+const LOG2_E: f64 = core::f64::consts::LOG2_E;
+const LN2_HI: f64 = 6.931471803691238e-1;
+const LN2_LO: f64 = 1.9082149292705877e-10;
+
+const FRAC_2_PI: f64 = core::f64::consts::FRAC_2_PI;
+/// π/2 em três partes (os 33 bits altos de cada parte tornam n·PIO2_k exato)
+const PIO2_1: f64 = 1.5707963267341256;
+const PIO2_2: f64 = 6.077100506303966e-11;
+const PIO2_3: f64 = 2.0222662487111665e-21;
+
+/// Termos das séries: |r| ≤ ln2/2 para exp e |r| ≤ π/4 para sin/cos
+const EXP_TERMS: u32 = 13;
+const TRIG_TERMS: u32 = 8;
+
+/// Inteiro mais próximo (meio para longe de zero), saturado em i64
+fn round_to_int(x: f64) -> i64 {
+    if x < 0.0 { (x - 0.5) as i64 } else { (x + 0.5) as i64 }
+}
+
+pub fn abs(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !(1u64 << 63))
+}
+
+/// x · 2^k para |k| até ~2100, passando por subnormais quando necessário
+fn scale_by_pow2(x: f64, k: i64) -> f64 {
+    let mut y = x;
+    let mut k = k;
+    for _ in 0..3 {
+        if k > 1023 {
+            y *= f64::from_bits(2046u64 << 52);
+            k -= 1023;
+        } else if k < -1022 {
+            y *= f64::from_bits(1u64 << 52);
+            k += 1022;
+        }
+    }
+    y * f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+pub fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > EXP_OVERFLOW {
+        return f64::INFINITY;
+    }
+    if x < EXP_UNDERFLOW {
+        return 0.0;
+    }
+    let k = round_to_int(x * LOG2_E);
+    let kf = k as f64;
+    let r = (x - kf * LN2_HI) - kf * LN2_LO;
+    // exp(r) = 1 + r(1 + r/2(1 + r/3(…)))
+    let mut p = 1.0;
+    for i in (1..=EXP_TERMS).rev() {
+        p = 1.0 + p * r / i as f64;
+    }
+    scale_by_pow2(p, k)
+}
+
+/// Reduz x a r ∈ [-π/4, π/4] e ao quadrante n mod 4.
+/// Exato para |x| ≤ 2^20·π/2; além disso a precisão degrada gradualmente.
+fn reduce_pio2(x: f64) -> (f64, i64) {
+    let n = round_to_int(x * FRAC_2_PI);
+    let nf = n as f64;
+    let r = ((x - nf * PIO2_1) - nf * PIO2_2) - nf * PIO2_3;
+    (r, n & 3)
+}
+
+fn sin_kernel(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut p = 1.0;
+    for j in (1..=TRIG_TERMS).rev() {
+        let j = j as f64;
+        p = 1.0 - p * r2 / ((2.0 * j) * (2.0 * j + 1.0));
+    }
+    r * p
+}
+
+fn cos_kernel(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut p = 1.0;
+    for j in (1..=TRIG_TERMS).rev() {
+        let j = j as f64;
+        p = 1.0 - p * r2 / ((2.0 * j - 1.0) * (2.0 * j));
+    }
+    p
+}
+
+/// (sin x, cos x) com uma única redução de argumento
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+    let (r, quadrant) = reduce_pio2(x);
+    let (s, c) = (sin_kernel(r), cos_kernel(r));
+    match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+pub fn sin(x: f64) -> f64 {
+    sin_cos(x).0
+}
+
+pub fn cos(x: f64) -> f64 {
+    sin_cos(x).1
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    /// Erro relativo em unidades de 2^-52 (absoluto perto de zero)
+    fn ulps(actual: f64, expected: f64) -> f64 {
+        abs(actual - expected) / (f64::EPSILON * abs(expected).max(f64::MIN_POSITIVE))
+    }
+
+    #[test]
+    fn exp_matches_the_platform_libm() {
+        for i in -2000..=2000 {
+            let x = i as f64 * 0.3417;
+            assert!(ulps(exp(x), x.exp()) <= 4.0, "exp({x}) = {} ≠ {}", exp(x), x.exp());
+        }
+        assert_eq!(exp(0.0), 1.0);
+        assert_eq!(exp(710.0), f64::INFINITY);
+        assert_eq!(exp(-746.0), 0.0);
+        assert!(exp(-740.0) > 0.0);
+        assert!(exp(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn sin_cos_match_the_platform_libm() {
+        for i in -5000..=5000 {
+            let x = i as f64 * 0.01237;
+            let (s, c) = sin_cos(x);
+            assert!(abs(s - x.sin()) <= 4.0 * f64::EPSILON, "sin({x}) = {s} ≠ {}", x.sin());
+            assert!(abs(c - x.cos()) <= 4.0 * f64::EPSILON, "cos({x}) = {c} ≠ {}", x.cos());
+        }
+        // Redução de argumento exata em múltiplos grandes de π/2
+        let far = 1.0e5;
+        assert!(abs(sin(far) - far.sin()) <= 1.0e-12);
+        assert!(sin(f64::INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,360 @@
+//! Árvore de Merkle do rol cardinal (`SASCIdentity::cardinal_merkle`)
+//! Folhas são as chaves públicas em ordem crescente; hashes no estilo RFC 6962:
+//! folha = SHA-256(0x00 ‖ chave), nó = SHA-256(0x01 ‖ esquerda ‖ direita). Um nó
+//! sem par sobe inalterado. Provas de inclusão têm tamanho fixo e dispensam alocação,
+//! de modo que nós leves validam um cardeal conhecendo apenas a raiz.
+
+use alloc::vec::Vec;
+
+use crate::crypto;
+
+/// Profundidade máxima de uma prova (até 2^16 folhas)
+pub const MAX_MERKLE_DEPTH: usize = 16;
+
+pub const MAX_MERKLE_LEAVES: usize = 1 << MAX_MERKLE_DEPTH;
+
+/// Tamanho da prova codificada: índice ‖ folhas ‖ profundidade ‖ irmãos
+pub const PROOF_LEN: usize = 9 + 32 * MAX_MERKLE_DEPTH;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+pub type Hash = [u8; 32];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleError {
+    TooManyLeaves,
+    DuplicateLeaf,
+    UnknownLeaf,
+    /// Prova codificada malformada
+    Malformed,
+}
+
+pub fn leaf_hash(key: &[u8; 32]) -> Hash {
+    let mut hasher = crypto::Sha256::new();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(key);
+    hasher.finalize()
+}
+
+pub fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = crypto::Sha256::new();
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize()
+}
+
+/// Raiz da árvore vazia: SHA-256 da mensagem vazia
+pub fn empty_root() -> Hash {
+    crypto::sha256(&[])
+}
+
+/// Reduz um nível in-place; devolve o tamanho do nível seguinte
+fn reduce(level: &mut [Hash], len: usize) -> usize {
+    let next = len.div_ceil(2);
+    for i in 0..next {
+        level[i] = if 2 * i + 1 < len { node_hash(&level[2 * i], &level[2 * i + 1]) } else { level[2 * i] };
+    }
+    next
+}
+
+/// Raiz do rol sem construir a árvore (as chaves são ordenadas antes); recusa
+/// os mesmos róis que `MerkleTree::new`
+pub fn root(keys: &[[u8; 32]]) -> Result<Hash, MerkleError> {
+    if keys.is_empty() {
+        return Ok(empty_root());
+    }
+    if keys.len() > MAX_MERKLE_LEAVES {
+        return Err(MerkleError::TooManyLeaves);
+    }
+    let mut sorted: Vec<[u8; 32]> = keys.to_vec();
+    sorted.sort_unstable();
+    if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(MerkleError::DuplicateLeaf);
+    }
+    let mut level: Vec<Hash> = sorted.iter().map(leaf_hash).collect();
+    let mut len = level.len();
+    // Cada redução ao menos divide por dois: usize::BITS níveis bastam
+    for _ in 0..usize::BITS {
+        if len <= 1 {
+            break;
+        }
+        len = reduce(&mut level, len);
+    }
+    Ok(level[0])
+}
+
+/// Prova de inclusão de uma folha
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MerkleProof {
+    pub index: u32,
+    pub leaf_count: u32,
+    pub depth: u8,
+    pub siblings: [Hash; MAX_MERKLE_DEPTH],
+}
+
+impl MerkleProof {
+    /// Recalcula a raiz a partir da chave; None se a prova for inconsistente
+    pub fn compute_root(&self, key: &[u8; 32]) -> Option<Hash> {
+        if self.index >= self.leaf_count || self.depth as usize > MAX_MERKLE_DEPTH {
+            return None;
+        }
+        let mut hash = leaf_hash(key);
+        let mut index = self.index;
+        let mut count = self.leaf_count;
+        let mut used = 0usize;
+        for _ in 0..MAX_MERKLE_DEPTH {
+            if count <= 1 {
+                break;
+            }
+            if index % 2 == 1 {
+                hash = node_hash(self.siblings.get(used)?, &hash);
+                used += 1;
+            } else if index + 1 < count {
+                hash = node_hash(&hash, self.siblings.get(used)?);
+                used += 1;
+            }
+            index /= 2;
+            count = count.div_ceil(2);
+        }
+        (count <= 1 && used == self.depth as usize).then_some(hash)
+    }
+
+    pub fn verify(&self, root: &Hash, key: &[u8; 32]) -> bool {
+        self.compute_root(key).is_some_and(|computed| crypto::ct_eq(&computed, root))
+    }
+
+    /// Codificação fixa (LE); irmãos não usados são zero
+    pub fn encode(&self) -> [u8; PROOF_LEN] {
+        let mut out = [0u8; PROOF_LEN];
+        out[..4].copy_from_slice(&self.index.to_le_bytes());
+        out[4..8].copy_from_slice(&self.leaf_count.to_le_bytes());
+        out[8] = self.depth;
+        for (chunk, sibling) in out[9..].chunks_exact_mut(32).zip(&self.siblings).take(self.depth as usize) {
+            chunk.copy_from_slice(sibling);
+        }
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, MerkleError> {
+        if bytes.len() != PROOF_LEN || bytes[8] as usize > MAX_MERKLE_DEPTH {
+            return Err(MerkleError::Malformed);
+        }
+        let mut word = [0u8; 4];
+        word.copy_from_slice(&bytes[..4]);
+        let index = u32::from_le_bytes(word);
+        word.copy_from_slice(&bytes[4..8]);
+        let leaf_count = u32::from_le_bytes(word);
+        let mut siblings = [[0u8; 32]; MAX_MERKLE_DEPTH];
+        for (sibling, chunk) in siblings.iter_mut().zip(bytes[9..].chunks_exact(32)) {
+            sibling.copy_from_slice(chunk);
+        }
+        Ok(Self { index, leaf_count, depth: bytes[8], siblings })
+    }
+}
+
+/// Árvore completa do rol, para quem emite provas
+#[derive(Debug, Clone, Default)]
+pub struct MerkleTree {
+    keys: Vec<[u8; 32]>,
+    /// Níveis do hash: `levels[0]` são as folhas, o último é a raiz
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    pub fn new(keys: &[[u8; 32]]) -> Result<Self, MerkleError> {
+        let mut tree = Self::default();
+        for key in keys {
+            tree.insert_key(*key)?;
+        }
+        tree.rebuild();
+        Ok(tree)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Chaves em ordem canônica
+    pub fn keys(&self) -> &[[u8; 32]] {
+        &self.keys
+    }
+
+    pub fn contains(&self, key: &[u8; 32]) -> bool {
+        self.keys.binary_search(key).is_ok()
+    }
+
+    pub fn root(&self) -> Hash {
+        self.levels.last().and_then(|level| level.first()).copied().unwrap_or_else(empty_root)
+    }
+
+    pub fn proof(&self, key: &[u8; 32]) -> Option<MerkleProof> {
+        let position = self.keys.binary_search(key).ok()?;
+        let mut siblings = [[0u8; 32]; MAX_MERKLE_DEPTH];
+        let mut depth = 0usize;
+        let mut index = position;
+        for level in self.levels.iter().take(self.levels.len().saturating_sub(1)) {
+            let sibling = if index % 2 == 1 { Some(index - 1) } else { Some(index + 1).filter(|i| *i < level.len()) };
+            if let Some(sibling) = sibling {
+                *siblings.get_mut(depth)? = level[sibling];
+                depth += 1;
+            }
+            index /= 2;
+        }
+        Some(MerkleProof { index: position as u32, leaf_count: self.keys.len() as u32, depth: depth as u8, siblings })
+    }
+
+    /// Inclui um cardeal; devolve a nova raiz
+    pub fn insert(&mut self, key: [u8; 32]) -> Result<Hash, MerkleError> {
+        self.insert_key(key)?;
+        self.rebuild();
+        Ok(self.root())
+    }
+
+    /// Remove um cardeal; devolve a nova raiz
+    pub fn remove(&mut self, key: &[u8; 32]) -> Result<Hash, MerkleError> {
+        let position = self.keys.binary_search(key).map_err(|_| MerkleError::UnknownLeaf)?;
+        self.keys.remove(position);
+        self.rebuild();
+        Ok(self.root())
+    }
+
+    /// Substitui a chave de um cardeal (rotação de chave); devolve a nova raiz
+    pub fn replace(&mut self, old: &[u8; 32], new: [u8; 32]) -> Result<Hash, MerkleError> {
+        if !self.contains(old) {
+            return Err(MerkleError::UnknownLeaf);
+        }
+        if self.contains(&new) {
+            return Err(MerkleError::DuplicateLeaf);
+        }
+        self.remove(old)?;
+        self.insert(new)
+    }
+
+    fn insert_key(&mut self, key: [u8; 32]) -> Result<(), MerkleError> {
+        let position = match self.keys.binary_search(&key) {
+            Ok(_) => return Err(MerkleError::DuplicateLeaf),
+            Err(position) => position,
+        };
+        if self.keys.len() >= MAX_MERKLE_LEAVES {
+            return Err(MerkleError::TooManyLeaves);
+        }
+        self.keys.insert(position, key);
+        Ok(())
+    }
+
+    fn rebuild(&mut self) {
+        self.levels.clear();
+        if self.keys.is_empty() {
+            return;
+        }
+        let mut level: Vec<Hash> = self.keys.iter().map(leaf_hash).collect();
+        for _ in 0..=MAX_MERKLE_DEPTH {
+            let len = level.len();
+            let mut next = level.clone();
+            let next_len = reduce(&mut next, len);
+            next.truncate(next_len);
+            self.levels.push(level);
+            if len <= 1 {
+                break;
+            }
+            level = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(count: u8) -> Vec<[u8; 32]> {
+        (1..=count).map(|i| [i; 32]).collect()
+    }
+
+    #[test]
+    fn proofs_verify_for_every_roster_shape() {
+        for count in [1u8, 2, 3, 5, 7, 8, 16] {
+            let keys = keys(count);
+            let tree = MerkleTree::new(&keys).unwrap();
+            assert_eq!(root(&keys), Ok(tree.root()));
+            let mut reversed = keys.clone();
+            reversed.reverse();
+            assert_eq!(root(&reversed), Ok(tree.root()));
+            for key in &keys {
+                let proof = tree.proof(key).unwrap();
+                assert!(proof.verify(&tree.root(), key), "{count} folhas");
+            }
+        }
+        let single = MerkleTree::new(&keys(1)).unwrap();
+        assert_eq!(single.root(), leaf_hash(&[1; 32]));
+        assert_eq!(single.proof(&[1; 32]).unwrap().depth, 0);
+        assert_eq!(root(&[]), Ok(empty_root()));
+    }
+
+    #[test]
+    fn proofs_reject_the_wrong_key_or_root() {
+        let tree = MerkleTree::new(&keys(5)).unwrap();
+        let proof = tree.proof(&[3; 32]).unwrap();
+        assert!(!proof.verify(&tree.root(), &[4; 32]));
+        let mut other = tree.root();
+        other[0] ^= 1;
+        assert!(!proof.verify(&other, &[3; 32]));
+        assert!(tree.proof(&[9; 32]).is_none());
+        let outside = MerkleProof { index: 5, ..proof };
+        assert_eq!(outside.compute_root(&[3; 32]), None);
+        let short = MerkleProof { depth: proof.depth - 1, ..proof };
+        assert_eq!(short.compute_root(&[3; 32]), None);
+    }
+
+    #[test]
+    fn proofs_round_trip_through_the_encoding() {
+        let tree = MerkleTree::new(&keys(7)).unwrap();
+        for key in tree.keys() {
+            let proof = tree.proof(key).unwrap();
+            assert_eq!(MerkleProof::decode(&proof.encode()), Ok(proof));
+        }
+        let bytes = tree.proof(&[1; 32]).unwrap().encode();
+        assert_eq!(MerkleProof::decode(&bytes[..PROOF_LEN - 1]), Err(MerkleError::Malformed));
+        let mut deep = bytes;
+        deep[8] = MAX_MERKLE_DEPTH as u8 + 1;
+        assert_eq!(MerkleProof::decode(&deep), Err(MerkleError::Malformed));
+    }
+
+    #[test]
+    fn roster_changes_move_the_root() {
+        let mut tree = MerkleTree::new(&keys(3)).unwrap();
+        let initial = tree.root();
+        let grown = tree.insert([4; 32]).unwrap();
+        assert_ne!(grown, initial);
+        assert_eq!(Ok(grown), root(&keys(4)));
+        assert_eq!(tree.insert([4; 32]), Err(MerkleError::DuplicateLeaf));
+
+        let rotated = tree.replace(&[4; 32], [9; 32]).unwrap();
+        assert_ne!(rotated, grown);
+        assert_eq!(Ok(rotated), root(&[[1; 32], [2; 32], [3; 32], [9; 32]]));
+        assert_eq!(tree.replace(&[4; 32], [5; 32]), Err(MerkleError::UnknownLeaf));
+        assert_eq!(tree.replace(&[9; 32], [1; 32]), Err(MerkleError::DuplicateLeaf));
+
+        assert_eq!(tree.remove(&[9; 32]), Ok(initial));
+        assert_eq!(tree.remove(&[9; 32]), Err(MerkleError::UnknownLeaf));
+        for key in keys(3) {
+            tree.remove(&key).unwrap();
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.root(), empty_root());
+    }
+
+    #[test]
+    fn root_rejects_what_the_tree_rejects() {
+        let repeated = [[1; 32], [2; 32], [1; 32]];
+        assert_eq!(root(&repeated), Err(MerkleError::DuplicateLeaf));
+        assert_eq!(MerkleTree::new(&repeated).err(), Some(MerkleError::DuplicateLeaf));
+        let crowded = alloc::vec![[0u8; 32]; MAX_MERKLE_LEAVES + 1];
+        assert_eq!(root(&crowded), Err(MerkleError::TooManyLeaves));
+    }
+}
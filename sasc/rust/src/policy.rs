@@ -0,0 +1,688 @@
+//! Linguagem de políticas de acesso
+//! Regras textuais, uma por linha, compiladas em AST e avaliadas sem recursão:
+//!
+//! ```text
+//! # comentário
+//! allow read when purpose == diagnostics and phi < 0.72
+//! deny write when sensitivity >= sovereign or time < 1000
+//! allow read, write when vote == approved and veto == released and coherence == stable
+//! default deny
+//! ```
+//!
+//! A primeira regra aplicável cuja condição é verdadeira decide: `allow` se aplica
+//! quando suas operações cobrem todo o pedido, `deny` quando alcançam qualquer
+//! operação pedida (um pedido `read, write` cai em `deny write`). Condições são
+//! disjunções de conjunções (`or` separa cláusulas, `and` separa termos), sem
+//! parênteses. Campos: `requester` (hex de 64 dígitos),
+//! `purpose`, `sensitivity`, `phi`, `vote`, `veto`, `coherence` e `time`.
+
+use alloc::vec::Vec;
+
+use crate::access::{AccessRequest, Operations, Purpose, Sensitivity};
+use crate::voting::VoteOutcome;
+
+/// Limites do texto compilado (Power of 10, regra 2)
+pub const MAX_POLICY_RULES: usize = 64;
+pub const MAX_POLICY_CLAUSES: usize = 8;
+pub const MAX_CLAUSE_TERMS: usize = 8;
+pub const MAX_POLICY_LINES: usize = 512;
+pub const MAX_LINE_TOKENS: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyErrorKind {
+    UnexpectedCharacter,
+    UnexpectedToken,
+    UnexpectedEnd,
+    UnknownEffect,
+    UnknownOperation,
+    UnknownField,
+    /// Operador não aplicável ao campo (ex.: `purpose < research`)
+    InvalidOperator,
+    InvalidValue,
+    DuplicateDefault,
+    TooManyRules,
+    TooManyClauses,
+    TooManyTerms,
+    TooManyLines,
+    LineTooLong,
+}
+
+/// Erro de compilação com posição de origem (ambas a partir de 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolicyError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: PolicyErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Requester,
+    Purpose,
+    Sensitivity,
+    Phi,
+    Vote,
+    Veto,
+    Coherence,
+    Time,
+}
+
+impl Field {
+    fn parse(word: &str) -> Option<Self> {
+        Some(match word {
+            "requester" => Field::Requester,
+            "purpose" => Field::Purpose,
+            "sensitivity" => Field::Sensitivity,
+            "phi" => Field::Phi,
+            "vote" => Field::Vote,
+            "veto" => Field::Veto,
+            "coherence" => Field::Coherence,
+            "time" => Field::Time,
+            _ => return None,
+        })
+    }
+
+    /// Campos categóricos aceitam apenas igualdade
+    fn ordered(self) -> bool {
+        matches!(self, Field::Sensitivity | Field::Phi | Field::Time)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    fn holds<V: PartialOrd>(self, left: V, right: V) -> bool {
+        match self {
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Gt => left > right,
+            Comparison::Ge => left >= right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Requester([u8; 32]),
+    Purpose(Purpose),
+    Sensitivity(Sensitivity),
+    Phi(f64),
+    Vote(VoteOutcome),
+    /// `veto == released`, `coherence == stable`
+    Flag(bool),
+    Time(u64),
+}
+
+/// Termo atômico: `campo operador valor`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Condition {
+    pub field: Field,
+    pub comparison: Comparison,
+    pub value: Value,
+}
+
+impl Condition {
+    fn holds(&self, ctx: &PolicyContext) -> bool {
+        let cmp = self.comparison;
+        match (self.field, self.value) {
+            (Field::Requester, Value::Requester(id)) => cmp.holds(ctx.request.requester == id, true),
+            (Field::Purpose, Value::Purpose(p)) => cmp.holds(ctx.request.purpose == p, true),
+            (Field::Sensitivity, Value::Sensitivity(s)) => cmp.holds(ctx.request.sensitivity, s),
+            // NaN (sem amostras) torna todo termo sobre Φ falso
+            (Field::Phi, Value::Phi(phi)) => cmp.holds(ctx.phi, phi),
+            (Field::Vote, Value::Vote(outcome)) => cmp.holds(ctx.vote == outcome, true),
+            (Field::Veto, Value::Flag(released)) => cmp.holds(ctx.veto_released == released, true),
+            (Field::Coherence, Value::Flag(stable)) => cmp.holds(ctx.coherence_stable == stable, true),
+            (Field::Time, Value::Time(t)) => cmp.holds(ctx.now, t),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub effect: Effect,
+    pub operations: Operations,
+    /// Forma normal disjuntiva; vazia = sempre verdadeira
+    pub clauses: Vec<Vec<Condition>>,
+    pub line: usize,
+}
+
+impl Rule {
+    /// Índice da primeira cláusula satisfeita
+    fn matching_clause(&self, ctx: &PolicyContext) -> Option<usize> {
+        let applies = match self.effect {
+            Effect::Allow => self.operations.contains(ctx.request.operations),
+            Effect::Deny => self.operations.bits() & ctx.request.operations.bits() != 0,
+        };
+        if !applies {
+            return None;
+        }
+        if self.clauses.is_empty() {
+            return Some(0);
+        }
+        self.clauses.iter().position(|clause| clause.iter().all(|c| c.holds(ctx)))
+    }
+}
+
+/// Fatos disponíveis à política no momento da decisão
+#[derive(Debug, Clone, Copy)]
+pub struct PolicyContext<'a> {
+    pub request: &'a AccessRequest,
+    pub phi: f64,
+    pub vote: VoteOutcome,
+    pub veto_released: bool,
+    pub coherence_stable: bool,
+    pub now: u64,
+}
+
+/// Regra que decidiu uma avaliação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiredRule {
+    pub index: usize,
+    pub line: usize,
+    pub clause: usize,
+}
+
+/// Resultado do dry-run: efeito e a regra responsável (None = `default`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation {
+    pub effect: Effect,
+    pub fired: Option<FiredRule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Policy {
+    pub rules: Vec<Rule>,
+    pub default: Effect,
+}
+
+impl Policy {
+    /// Compila o texto; falha no primeiro erro
+    pub fn parse(source: &str) -> Result<Self, PolicyError> {
+        let mut policy = Policy { rules: Vec::new(), default: Effect::Deny };
+        let mut default_line = None;
+
+        for (index, text) in source.lines().enumerate() {
+            let line = index + 1;
+            if line > MAX_POLICY_LINES {
+                return Err(PolicyError { line, column: 1, kind: PolicyErrorKind::TooManyLines });
+            }
+            match parse_line(text, line)? {
+                Line::Blank => {}
+                Line::Default(effect, column) => {
+                    if default_line.is_some() {
+                        return Err(PolicyError { line, column, kind: PolicyErrorKind::DuplicateDefault });
+                    }
+                    default_line = Some(line);
+                    policy.default = effect;
+                }
+                Line::Rule(rule) => {
+                    if policy.rules.len() >= MAX_POLICY_RULES {
+                        return Err(PolicyError { line, column: 1, kind: PolicyErrorKind::TooManyRules });
+                    }
+                    policy.rules.push(rule);
+                }
+            }
+        }
+        Ok(policy)
+    }
+
+    /// Valida o texto inteiro, relatando um erro por linha inválida
+    pub fn validate(source: &str) -> Vec<PolicyError> {
+        let mut errors = Vec::new();
+        let mut rules = 0usize;
+        let mut defaults = 0usize;
+        for (index, text) in source.lines().enumerate() {
+            let line = index + 1;
+            if line > MAX_POLICY_LINES {
+                errors.push(PolicyError { line, column: 1, kind: PolicyErrorKind::TooManyLines });
+                break;
+            }
+            match parse_line(text, line) {
+                Ok(Line::Blank) => {}
+                Ok(Line::Default(_, column)) => {
+                    defaults += 1;
+                    if defaults > 1 {
+                        errors.push(PolicyError { line, column, kind: PolicyErrorKind::DuplicateDefault });
+                    }
+                }
+                Ok(Line::Rule(_)) => {
+                    rules += 1;
+                    if rules > MAX_POLICY_RULES {
+                        errors.push(PolicyError { line, column: 1, kind: PolicyErrorKind::TooManyRules });
+                    }
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+        errors
+    }
+
+    /// Dry-run: avalia sem efeitos colaterais e indica a regra que decidiu
+    pub fn explain(&self, ctx: &PolicyContext) -> Explanation {
+        for (index, rule) in self.rules.iter().enumerate() {
+            if let Some(clause) = rule.matching_clause(ctx) {
+                return Explanation {
+                    effect: rule.effect,
+                    fired: Some(FiredRule { index, line: rule.line, clause }),
+                };
+            }
+        }
+        Explanation { effect: self.default, fired: None }
+    }
+
+    pub fn evaluate(&self, ctx: &PolicyContext) -> Effect {
+        self.explain(ctx).effect
+    }
+}
+
+// ============================================================================
+// ANÁLISE LÉXICA E SINTÁTICA (linha a linha, sem recursão)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    /// Identificador, número ou hex: sequência de `[A-Za-z0-9_.]`
+    Word,
+    Op(Comparison),
+    Comma,
+    Star,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    column: usize,
+}
+
+enum Line {
+    Blank,
+    Default(Effect, usize),
+    Rule(Rule),
+}
+
+fn tokenize(text: &str, line: usize) -> Result<Vec<Token<'_>>, PolicyError> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let error = |i: usize, kind| PolicyError { line, column: text[..i].chars().count() + 1, kind };
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'#' {
+            break;
+        }
+        if b == b' ' || b == b'\t' || b == b'\r' {
+            i += 1;
+            continue;
+        }
+        if tokens.len() >= MAX_LINE_TOKENS {
+            return Err(error(i, PolicyErrorKind::LineTooLong));
+        }
+        let start = i;
+        let kind = if b.is_ascii_alphanumeric() || b == b'_' || b == b'.' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.') {
+                i += 1;
+            }
+            TokenKind::Word
+        } else {
+            let next = bytes.get(i + 1).copied();
+            let (kind, len) = match (b, next) {
+                (b'=', Some(b'=')) => (TokenKind::Op(Comparison::Eq), 2),
+                (b'!', Some(b'=')) => (TokenKind::Op(Comparison::Ne), 2),
+                (b'<', Some(b'=')) => (TokenKind::Op(Comparison::Le), 2),
+                (b'>', Some(b'=')) => (TokenKind::Op(Comparison::Ge), 2),
+                (b'<', _) => (TokenKind::Op(Comparison::Lt), 1),
+                (b'>', _) => (TokenKind::Op(Comparison::Gt), 1),
+                (b',', _) => (TokenKind::Comma, 1),
+                (b'*', _) => (TokenKind::Star, 1),
+                _ => return Err(error(i, PolicyErrorKind::UnexpectedCharacter)),
+            };
+            i += len;
+            kind
+        };
+        tokens.push(Token { kind, text: &text[start..i], column: text[..start].chars().count() + 1 });
+    }
+    Ok(tokens)
+}
+
+/// Cursor sobre os tokens de uma linha
+struct Cursor<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    line: usize,
+    /// Coluna logo após o fim da linha, para erros de fim inesperado
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn next(&mut self) -> Result<Token<'a>, PolicyError> {
+        let token = self.tokens.get(self.pos).copied().ok_or(PolicyError {
+            line: self.line,
+            column: self.end,
+            kind: PolicyErrorKind::UnexpectedEnd,
+        })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn error(&self, token: Token, kind: PolicyErrorKind) -> PolicyError {
+        PolicyError { line: self.line, column: token.column, kind }
+    }
+
+    fn word(&mut self) -> Result<Token<'a>, PolicyError> {
+        let token = self.next()?;
+        if token.kind != TokenKind::Word {
+            return Err(self.error(token, PolicyErrorKind::UnexpectedToken));
+        }
+        Ok(token)
+    }
+}
+
+fn parse_line(text: &str, line: usize) -> Result<Line, PolicyError> {
+    let tokens = tokenize(text, line)?;
+    let end = text.chars().count() + 1;
+    let mut cursor = Cursor { tokens, pos: 0, line, end };
+
+    let Some(first) = cursor.peek() else {
+        return Ok(Line::Blank);
+    };
+    let head = cursor.word()?;
+    if head.text == "default" {
+        let token = cursor.word()?;
+        let effect = parse_effect(&cursor, token)?;
+        if let Some(extra) = cursor.peek() {
+            return Err(cursor.error(extra, PolicyErrorKind::UnexpectedToken));
+        }
+        return Ok(Line::Default(effect, first.column));
+    }
+    let effect = parse_effect(&cursor, head)?;
+
+    // Operações: `*` ou lista separada por vírgulas
+    let mut operations = Operations::NONE;
+    loop {
+        let token = cursor.next()?;
+        let op = match (token.kind, token.text) {
+            (TokenKind::Star, _) => Operations::READ_WRITE,
+            (TokenKind::Word, "read") => Operations::READ,
+            (TokenKind::Word, "write") => Operations::WRITE,
+            _ => return Err(cursor.error(token, PolicyErrorKind::UnknownOperation)),
+        };
+        operations = operations.union(op);
+        match cursor.peek() {
+            Some(t) if t.kind == TokenKind::Comma => cursor.pos += 1,
+            _ => break,
+        }
+    }
+
+    let mut clauses: Vec<Vec<Condition>> = Vec::new();
+    if let Some(token) = cursor.peek() {
+        if token.kind != TokenKind::Word || token.text != "when" {
+            return Err(cursor.error(token, PolicyErrorKind::UnexpectedToken));
+        }
+        cursor.pos += 1;
+        clauses.push(Vec::new());
+        loop {
+            let condition = parse_condition(&mut cursor)?;
+            let clause = clauses.last_mut().ok_or(PolicyError {
+                line,
+                column: end,
+                kind: PolicyErrorKind::UnexpectedEnd,
+            })?;
+            if clause.len() >= MAX_CLAUSE_TERMS {
+                return Err(PolicyError { line, column: end, kind: PolicyErrorKind::TooManyTerms });
+            }
+            clause.push(condition);
+
+            let Some(joiner) = cursor.peek() else {
+                break;
+            };
+            cursor.pos += 1;
+            match (joiner.kind, joiner.text) {
+                (TokenKind::Word, "and") => {}
+                (TokenKind::Word, "or") => {
+                    if clauses.len() >= MAX_POLICY_CLAUSES {
+                        return Err(cursor.error(joiner, PolicyErrorKind::TooManyClauses));
+                    }
+                    clauses.push(Vec::new());
+                }
+                _ => return Err(cursor.error(joiner, PolicyErrorKind::UnexpectedToken)),
+            }
+        }
+    }
+
+    Ok(Line::Rule(Rule { effect, operations, clauses, line }))
+}
+
+fn parse_effect(cursor: &Cursor, token: Token) -> Result<Effect, PolicyError> {
+    match token.text {
+        "allow" => Ok(Effect::Allow),
+        "deny" => Ok(Effect::Deny),
+        _ => Err(cursor.error(token, PolicyErrorKind::UnknownEffect)),
+    }
+}
+
+fn parse_condition(cursor: &mut Cursor) -> Result<Condition, PolicyError> {
+    let name = cursor.word()?;
+    let field = Field::parse(name.text).ok_or(cursor.error(name, PolicyErrorKind::UnknownField))?;
+
+    let op = cursor.next()?;
+    let TokenKind::Op(comparison) = op.kind else {
+        return Err(cursor.error(op, PolicyErrorKind::UnexpectedToken));
+    };
+    if !field.ordered() && !matches!(comparison, Comparison::Eq | Comparison::Ne) {
+        return Err(cursor.error(op, PolicyErrorKind::InvalidOperator));
+    }
+
+    let token = cursor.word()?;
+    let invalid = cursor.error(token, PolicyErrorKind::InvalidValue);
+    let value = match field {
+        Field::Requester => Value::Requester(parse_hex(token.text).ok_or(invalid)?),
+        Field::Purpose => Value::Purpose(match token.text {
+            "diagnostics" => Purpose::Diagnostics,
+            "maintenance" => Purpose::Maintenance,
+            "research" => Purpose::Research,
+            "governance" => Purpose::Governance,
+            "emergency" => Purpose::Emergency,
+            _ => return Err(invalid),
+        }),
+        Field::Sensitivity => Value::Sensitivity(match token.text {
+            "public" => Sensitivity::Public,
+            "internal" => Sensitivity::Internal,
+            "confidential" => Sensitivity::Confidential,
+            "sovereign" => Sensitivity::Sovereign,
+            _ => return Err(invalid),
+        }),
+        Field::Phi => match token.text.parse::<f64>() {
+            Ok(phi) if phi.is_finite() && token.text.as_bytes()[0].is_ascii_digit() => Value::Phi(phi),
+            _ => return Err(invalid),
+        },
+        Field::Vote => Value::Vote(match token.text {
+            "approved" => VoteOutcome::Approved,
+            "rejected" => VoteOutcome::Rejected,
+            "pending" => VoteOutcome::Pending,
+            _ => return Err(invalid),
+        }),
+        Field::Veto => Value::Flag(match token.text {
+            "released" => true,
+            "armed" => false,
+            _ => return Err(invalid),
+        }),
+        Field::Coherence => Value::Flag(match token.text {
+            "stable" => true,
+            "unstable" => false,
+            _ => return Err(invalid),
+        }),
+        Field::Time => Value::Time(token.text.parse::<u64>().map_err(|_| invalid)?),
+    };
+    Ok(Condition { field, comparison, value })
+}
+
+fn parse_hex(text: &str) -> Option<[u8; 32]> {
+    let bytes = text.as_bytes();
+    if bytes.len() != 64 {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        let hi = (bytes[2 * i] as char).to_digit(16)?;
+        let lo = (bytes[2 * i + 1] as char).to_digit(16)?;
+        *byte = (hi * 16 + lo) as u8;
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PolicyErrorKind as K;
+
+    fn error(source: &str) -> (usize, usize, PolicyErrorKind) {
+        let e = Policy::parse(source).unwrap_err();
+        (e.line, e.column, e.kind)
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        assert_eq!(error("allow read when phi < 0.72\npermit read"), (2, 1, K::UnknownEffect));
+        assert_eq!(error("allow list"), (1, 7, K::UnknownOperation));
+        assert_eq!(error("allow read when colour == red"), (1, 17, K::UnknownField));
+        assert_eq!(error("allow read when purpose < research"), (1, 25, K::InvalidOperator));
+        assert_eq!(error("allow read when phi < high"), (1, 23, K::InvalidValue));
+        assert_eq!(error("allow read when phi < -1"), (1, 23, K::UnexpectedCharacter));
+        assert_eq!(error("allow read when phi <"), (1, 22, K::UnexpectedEnd));
+        assert_eq!(error("allow read unless phi < 1"), (1, 12, K::UnexpectedToken));
+        assert_eq!(error("allow read when phi < 1 xor time > 3"), (1, 25, K::UnexpectedToken));
+        assert_eq!(error("default deny\n\n  default allow"), (3, 3, K::DuplicateDefault));
+        assert_eq!(error("default deny now"), (1, 14, K::UnexpectedToken));
+    }
+
+    #[test]
+    fn columns_count_characters_not_bytes() {
+        // "ç" ocupa dois bytes e uma coluna
+        assert_eq!(error("# ç\nallow read when purpose == ação"), (2, 29, K::UnexpectedCharacter));
+        assert_eq!(error("allow read when purpose == diagnostics # ção\ndeny ç"), (2, 6, K::UnexpectedCharacter));
+    }
+
+    #[test]
+    fn limits_are_reported_with_position() {
+        let terms = ["time > 1"; MAX_CLAUSE_TERMS + 1].join(" and ");
+        let source = alloc::format!("allow read when {terms}");
+        assert_eq!(error(&source), (1, source.chars().count() + 1, K::TooManyTerms));
+
+        let clauses = ["time > 1"; MAX_POLICY_CLAUSES + 1].join(" or ");
+        let column = alloc::format!("allow read when {}", ["time > 1"; MAX_POLICY_CLAUSES].join(" or ")).len() + 2;
+        assert_eq!(error(&alloc::format!("allow read when {clauses}")), (1, column, K::TooManyClauses));
+
+        let rules = ["allow read"; MAX_POLICY_RULES + 1].join("\n");
+        assert_eq!(error(&rules), (MAX_POLICY_RULES + 1, 1, K::TooManyRules));
+        let lines = ["# nada"; MAX_POLICY_LINES + 1].join("\n");
+        assert_eq!(error(&lines), (MAX_POLICY_LINES + 1, 1, K::TooManyLines));
+        let tokens = ["*"; MAX_LINE_TOKENS + 1].join(" ");
+        assert_eq!(error(&tokens), (1, 2 * MAX_LINE_TOKENS + 1, K::LineTooLong));
+    }
+
+    #[test]
+    fn validate_reports_every_line() {
+        let source = "allow read when phi < x\nallow read\ndeny writ\ndefault deny\ndefault deny";
+        let errors = Policy::validate(source);
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column, e.kind)).collect();
+        assert_eq!(found, [(1, 23, K::InvalidValue), (3, 6, K::UnknownOperation), (5, 1, K::DuplicateDefault)]);
+        assert!(Policy::validate("allow read\ndefault deny").is_empty());
+    }
+
+    fn request(purpose: Purpose, operations: Operations, sensitivity: Sensitivity) -> AccessRequest {
+        let key = crate::crypto::SigningKey::from_seed(&[5; 32]);
+        AccessRequest::sign(&key, purpose, operations, sensitivity, alloc::string::String::new())
+    }
+
+    fn context(request: &AccessRequest, phi: f64) -> PolicyContext<'_> {
+        PolicyContext {
+            request,
+            phi,
+            vote: VoteOutcome::Approved,
+            veto_released: true,
+            coherence_stable: true,
+            now: 2_000,
+        }
+    }
+
+    const DOC_POLICY: &str = "# comentário
+allow read when purpose == diagnostics and phi < 0.72
+deny write when sensitivity >= sovereign or time < 1000
+allow read, write when vote == approved and veto == released and coherence == stable
+default deny";
+
+    #[test]
+    fn first_matching_rule_decides() {
+        let policy = Policy::parse(DOC_POLICY).unwrap();
+        let read = request(Purpose::Diagnostics, Operations::READ, Sensitivity::Public);
+        let explanation = policy.explain(&context(&read, 0.5));
+        assert_eq!(explanation.effect, Effect::Allow);
+        assert_eq!(explanation.fired, Some(FiredRule { index: 0, line: 2, clause: 0 }));
+
+        // Φ alto: a primeira regra falha e a terceira decide
+        let explanation = policy.explain(&context(&read, 0.9));
+        assert_eq!(explanation.fired, Some(FiredRule { index: 2, line: 4, clause: 0 }));
+
+        let sovereign = request(Purpose::Maintenance, Operations::WRITE, Sensitivity::Sovereign);
+        let explanation = policy.explain(&context(&sovereign, 0.5));
+        assert_eq!(explanation.effect, Effect::Deny);
+        assert_eq!(explanation.fired, Some(FiredRule { index: 1, line: 3, clause: 0 }));
+        assert_eq!(policy.evaluate(&context(&sovereign, 0.5)), Effect::Deny);
+    }
+
+    #[test]
+    fn default_applies_when_no_rule_fires() {
+        let policy = Policy::parse(DOC_POLICY).unwrap();
+        let read = request(Purpose::Diagnostics, Operations::READ, Sensitivity::Public);
+        let mut ctx = context(&read, 0.9);
+        ctx.vote = VoteOutcome::Pending;
+        assert_eq!(policy.explain(&ctx), Explanation { effect: Effect::Deny, fired: None });
+
+        let open = Policy::parse("deny write
+default allow").unwrap();
+        assert_eq!(open.explain(&ctx), Explanation { effect: Effect::Allow, fired: None });
+        // NaN (sem amostras) torna falsa toda condição sobre Φ
+        assert_eq!(Policy::parse("allow read when phi < 1").unwrap().evaluate(&context(&read, f64::NAN)), Effect::Deny);
+    }
+
+    #[test]
+    fn deny_fires_on_overlapping_operations() {
+        let policy = Policy::parse(DOC_POLICY).unwrap();
+        let both = request(Purpose::Maintenance, Operations::READ_WRITE, Sensitivity::Sovereign);
+        let explanation = policy.explain(&context(&both, 0.5));
+        assert_eq!(explanation.effect, Effect::Deny);
+        assert_eq!(explanation.fired.map(|f| f.line), Some(3));
+
+        // `allow` continua exigindo cobertura total do pedido
+        let partial = Policy::parse("allow read
+default deny").unwrap();
+        assert_eq!(partial.explain(&context(&both, 0.5)).fired, None);
+        let full = Policy::parse("allow read, write
+default deny").unwrap();
+        assert_eq!(full.evaluate(&context(&both, 0.5)), Effect::Allow);
+    }
+}
@@ -148,6 +148,12 @@ impl ConsciousnessChannel {
 }
 
 pub struct TunnelProjection;
+impl Default for TunnelProjection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TunnelProjection {
     pub fn new() -> Self { Self }
     pub fn encode(&self, _t: &impl Tunnel) -> Result<u32, SSHError> { Ok(0) }
@@ -160,6 +166,12 @@ impl SecurityContext {
 }
 
 pub struct ConsciousnessProjection;
+impl Default for ConsciousnessProjection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ConsciousnessProjection {
     pub fn new() -> Self { Self }
     pub fn add_pattern(&mut self, _p: u32) {}
@@ -508,15 +520,15 @@ impl SSHOAuthConstitution {
     }
 
     fn count_active_local_tunnels(&self) -> u32 {
-        self.local_tunnels.iter().filter(|t| t.as_ref().map_or(false, |tt| tt.active)).count() as u32
+        self.local_tunnels.iter().filter(|t| t.as_ref().is_some_and(|tt| tt.active)).count() as u32
     }
 
     fn count_active_remote_tunnels(&self) -> u32 {
-        self.remote_tunnels.iter().filter(|t| t.as_ref().map_or(false, |tt| tt.active)).count() as u32
+        self.remote_tunnels.iter().filter(|t| t.as_ref().is_some_and(|tt| tt.active)).count() as u32
     }
 
     fn count_active_dynamic_tunnels(&self) -> u32 {
-        self.dynamic_tunnels.iter().filter(|t| t.as_ref().map_or(false, |tt| tt.active)).count() as u32
+        self.dynamic_tunnels.iter().filter(|t| t.as_ref().is_some_and(|tt| tt.active)).count() as u32
     }
 
     fn current_timestamp() -> u64 { 0 }
@@ -576,6 +588,12 @@ pub struct QuantumValidationState {
     pub coherence_time: f64,
 }
 
+impl Default for QuantumValidationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl QuantumValidationState {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,254 @@
+//! Exclusão mútua `no_std` sobre `core::sync::atomic`
+//! Spinlock com tentativas limitadas (Power of 10, regra 2), envenenamento
+//! e protocolo opcional de teto de prioridade para integração com RTOS.
+
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Limite padrão de giros usado por `lock()`
+pub const DEFAULT_SPIN_LIMIT: u32 = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockError {
+    /// O limite de giros foi atingido sem adquirir o lock
+    WouldBlock,
+    /// Um detentor anterior entrou em pânico ou envenenou o lock
+    Poisoned,
+}
+
+/// Ganchos do RTOS para o protocolo de teto de prioridade imediato:
+/// `raise` eleva a tarefa corrente ao teto e devolve a prioridade anterior,
+/// `restore` reinstala essa prioridade ao liberar o lock.
+#[derive(Clone, Copy)]
+pub struct PriorityCeiling {
+    pub ceiling: u8,
+    pub raise: fn(u8) -> u8,
+    pub restore: fn(u8),
+}
+
+pub struct Mutex<T> {
+    locked: AtomicBool,
+    poisoned: AtomicBool,
+    ceiling: Option<PriorityCeiling>,
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: o acesso a `data` é serializado por `locked`; só um guard existe por vez.
+#[allow(unsafe_code)]
+unsafe impl<T: Send> Send for Mutex<T> {}
+#[allow(unsafe_code)]
+unsafe impl<T: Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    pub const fn new(data: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            poisoned: AtomicBool::new(false),
+            ceiling: None,
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Mutex com teto de prioridade: o detentor roda na prioridade `ceiling`
+    pub const fn with_priority_ceiling(data: T, ceiling: PriorityCeiling) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            poisoned: AtomicBool::new(false),
+            ceiling: Some(ceiling),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Adquire o lock girando no máximo `DEFAULT_SPIN_LIMIT` vezes
+    pub fn lock(&self) -> Result<MutexGuard<'_, T>, LockError> {
+        self.try_lock(DEFAULT_SPIN_LIMIT)
+    }
+
+    /// Tenta adquirir o lock em no máximo `max_spins` tentativas
+    pub fn try_lock(&self, max_spins: u32) -> Result<MutexGuard<'_, T>, LockError> {
+        let previous_priority = self.ceiling.map(|c| (c.raise)(c.ceiling));
+
+        let mut spins = 0u32;
+        loop {
+            if self
+                .locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                break;
+            }
+            spins = spins.saturating_add(1);
+            if spins >= max_spins {
+                if let (Some(c), Some(p)) = (self.ceiling, previous_priority) {
+                    (c.restore)(p);
+                }
+                return Err(LockError::WouldBlock);
+            }
+            core::hint::spin_loop();
+        }
+
+        let guard = MutexGuard { mutex: self, previous_priority, _not_send: PhantomData };
+        if self.poisoned.load(Ordering::Acquire) {
+            return Err(LockError::Poisoned);
+        }
+        Ok(guard)
+    }
+
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Remove o envenenamento após o chamador restaurar um estado consistente
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::Release);
+    }
+
+    /// Acesso exclusivo estático: `&mut self` já garante ausência de outros detentores
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+/// Guard RAII: libera o lock (e restaura a prioridade) ao sair de escopo.
+/// Não é `Send`: a prioridade elevada pertence à tarefa que adquiriu o lock
+/// e deve ser restaurada por ela.
+pub struct MutexGuard<'a, T> {
+    mutex: &'a Mutex<T>,
+    previous_priority: Option<u8>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: `&MutexGuard<T>` só expõe `&T`; compartilhá-lo entre threads exige `T: Sync`.
+#[allow(unsafe_code)]
+unsafe impl<T: Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T> MutexGuard<'_, T> {
+    /// Envenena explicitamente o lock (alvos `no_std` sem desenrolamento de pânico)
+    pub fn poison(self) {
+        self.mutex.poisoned.store(true, Ordering::Release);
+    }
+}
+
+impl<T> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    #[allow(unsafe_code)]
+    fn deref(&self) -> &T {
+        // SAFETY: o guard só existe enquanto `locked` pertence a este detentor.
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T> DerefMut for MutexGuard<'_, T> {
+    #[allow(unsafe_code)]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: idem; `&mut self` impede aliasing através do mesmo guard.
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        {
+            if std::thread::panicking() {
+                self.mutex.poisoned.store(true, Ordering::Release);
+            }
+        }
+        self.mutex.locked.store(false, Ordering::Release);
+        if let (Some(c), Some(p)) = (self.mutex.ceiling, self.previous_priority) {
+            (c.restore)(p);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::thread;
+
+    /// Sob contenção o giro limitado pode expirar; o teste cede e tenta de novo
+    fn acquire<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        loop {
+            match mutex.try_lock(1 << 10) {
+                Ok(guard) => return guard,
+                Err(LockError::WouldBlock) => thread::yield_now(),
+                Err(LockError::Poisoned) => panic!("lock envenenado"),
+            }
+        }
+    }
+
+    #[test]
+    fn lock_excludes_concurrent_holders() {
+        const THREADS: usize = 8;
+        const ROUNDS: usize = 500;
+        let counter = Mutex::new(0usize);
+        let holders = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..ROUNDS {
+                        let mut guard = acquire(&counter);
+                        assert_eq!(holders.fetch_add(1, Ordering::SeqCst), 0);
+                        // leitura-modificação-escrita não atômica: só é correta sob exclusão
+                        let value = *guard;
+                        thread::yield_now();
+                        *guard = value + 1;
+                        holders.fetch_sub(1, Ordering::SeqCst);
+                    }
+                });
+            }
+        });
+        assert_eq!(counter.into_inner(), THREADS * ROUNDS);
+    }
+
+    #[test]
+    fn try_lock_gives_up_while_held() {
+        let mutex = Mutex::new(());
+        let guard = mutex.lock().unwrap();
+        thread::scope(|scope| {
+            let outcome = scope.spawn(|| mutex.try_lock(16).map(|_| ())).join().unwrap();
+            assert_eq!(outcome, Err(LockError::WouldBlock));
+        });
+        drop(guard);
+        assert!(mutex.try_lock(1).is_ok());
+    }
+
+    #[test]
+    fn panic_while_held_poisons() {
+        let mutex = Mutex::new(0u8);
+        thread::scope(|scope| {
+            let result = scope.spawn(|| {
+                let _guard = mutex.lock().unwrap();
+                panic!("detentor falhou");
+            });
+            assert!(result.join().is_err());
+        });
+        assert!(mutex.is_poisoned());
+        assert_eq!(mutex.lock().err(), Some(LockError::Poisoned));
+        mutex.clear_poison();
+        assert!(mutex.lock().is_ok());
+    }
+
+    #[test]
+    fn explicit_poison() {
+        let mutex = Mutex::new(());
+        mutex.lock().unwrap().poison();
+        assert_eq!(mutex.try_lock(1).err(), Some(LockError::Poisoned));
+    }
+
+    #[test]
+    fn guard_is_sync_only_for_sync_data() {
+        fn assert_sync<S: Sync>() {}
+        assert_sync::<MutexGuard<'static, u32>>();
+        assert_sync::<Mutex<core::cell::Cell<u32>>>();
+        // `MutexGuard<Cell<_>>` e o envio do guard são rejeitados: tests/ui/sync_guard_*.rs
+    }
+}
@@ -0,0 +1,268 @@
+//! Veto do Príncipe expresso como tokens Ed25519
+//! Cada liberação ou rearme é assinado pela `prince_key`, vinculado a um propósito,
+//! ao `temporal_nonce` corrente e a uma janela de validade.
+
+use crate::access::AccessRequest;
+use crate::crypto;
+use crate::gates::{SASCIdentity, VetoStatus};
+
+/// Domínio de separação das mensagens de veto
+pub const VETO_DOMAIN: &[u8] = b"SASC-VETO-v1";
+
+/// Tamanho da mensagem canônica assinada
+pub const VETO_MESSAGE_LEN: usize = 38;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VetoAction {
+    Release,
+    Arm,
+}
+
+/// Propósito (escopo) ao qual uma liberação se aplica
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VetoScope {
+    DataAccess,
+    PhaseTransition,
+    ContainmentThaw,
+}
+
+impl VetoScope {
+    pub const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        match self {
+            VetoScope::DataAccess => 0,
+            VetoScope::PhaseTransition => 1,
+            VetoScope::ContainmentThaw => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VetoError {
+    InvalidSignature,
+    Expired,
+    NotYetValid,
+    Replayed,
+    NonceMismatch,
+    Armed,
+}
+
+/// Token de veto assinado pelo Príncipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VetoToken {
+    pub action: VetoAction,
+    pub scope: VetoScope,
+    pub nonce: u64,
+    pub issued_at: u64,
+    pub expires_at: u64,
+    pub signature: [u8; 64],
+}
+
+impl VetoToken {
+    /// Mensagem canônica: domínio ‖ ação ‖ escopo ‖ nonce ‖ emissão ‖ expiração (LE)
+    pub fn signing_message(
+        action: VetoAction,
+        scope: VetoScope,
+        nonce: u64,
+        issued_at: u64,
+        expires_at: u64,
+    ) -> [u8; VETO_MESSAGE_LEN] {
+        let mut msg = [0u8; VETO_MESSAGE_LEN];
+        msg[..12].copy_from_slice(VETO_DOMAIN);
+        msg[12] = match action {
+            VetoAction::Release => 1,
+            VetoAction::Arm => 2,
+        };
+        msg[13] = scope.index() as u8;
+        msg[14..22].copy_from_slice(&nonce.to_le_bytes());
+        msg[22..30].copy_from_slice(&issued_at.to_le_bytes());
+        msg[30..38].copy_from_slice(&expires_at.to_le_bytes());
+        msg
+    }
+
+    pub fn sign(
+        prince: &crypto::SigningKey,
+        action: VetoAction,
+        scope: VetoScope,
+        nonce: u64,
+        issued_at: u64,
+        expires_at: u64,
+    ) -> Self {
+        let msg = Self::signing_message(action, scope, nonce, issued_at, expires_at);
+        Self { action, scope, nonce, issued_at, expires_at, signature: prince.sign(&msg) }
+    }
+
+    pub fn verify_signature(&self, prince_key: &[u8; 32]) -> bool {
+        let msg = Self::signing_message(self.action, self.scope, self.nonce, self.issued_at, self.expires_at);
+        crypto::verify(prince_key, &msg, &self.signature)
+    }
+
+    fn within_window(&self, now: u64) -> Result<(), VetoError> {
+        if now < self.issued_at {
+            return Err(VetoError::NotYetValid);
+        }
+        if now >= self.expires_at {
+            return Err(VetoError::Expired);
+        }
+        Ok(())
+    }
+}
+
+/// Controle de veto: guarda as liberações aceitas por escopo
+pub struct VetoControl {
+    pub prince_key: [u8; 32],
+    temporal_nonce: u64,
+    releases: [Option<VetoToken>; VetoScope::COUNT],
+}
+
+impl VetoControl {
+    pub fn new(identity: &SASCIdentity) -> Self {
+        Self {
+            prince_key: identity.prince_key,
+            temporal_nonce: identity.temporal_nonce,
+            releases: [None; VetoScope::COUNT],
+        }
+    }
+
+    /// Próximo nonce aceitável; cada token aceito o consome
+    pub fn temporal_nonce(&self) -> u64 {
+        self.temporal_nonce
+    }
+
+    /// Aplica um token de liberação ou rearme, rejeitando tokens inválidos ou reutilizados
+    pub fn apply(&mut self, token: VetoToken, now: u64) -> Result<VetoStatus, VetoError> {
+        if !token.verify_signature(&self.prince_key) {
+            return Err(VetoError::InvalidSignature);
+        }
+        if token.nonce < self.temporal_nonce {
+            return Err(VetoError::Replayed);
+        }
+        if token.nonce != self.temporal_nonce {
+            return Err(VetoError::NonceMismatch);
+        }
+        token.within_window(now)?;
+
+        self.temporal_nonce = self.temporal_nonce.wrapping_add(1);
+        let slot = &mut self.releases[token.scope.index()];
+        match token.action {
+            VetoAction::Release => {
+                *slot = Some(token);
+                Ok(VetoStatus::ExplicitlyReleased)
+            }
+            VetoAction::Arm => {
+                *slot = None;
+                Ok(VetoStatus::Active)
+            }
+        }
+    }
+
+    /// Verifica se existe liberação válida para o escopo no instante `now`
+    pub fn verify_release(&self, scope: VetoScope, now: u64) -> Result<(), VetoError> {
+        // O slot é indexado pelo escopo assinado: uma liberação nunca serve a outro escopo
+        let token = self.releases[scope.index()].ok_or(VetoError::Armed)?;
+        if !token.verify_signature(&self.prince_key) {
+            return Err(VetoError::InvalidSignature);
+        }
+        token.within_window(now)
+    }
+
+    pub fn is_released(&self, scope: VetoScope, now: u64) -> bool {
+        self.verify_release(scope, now).is_ok()
+    }
+
+    /// Avalia um pedido de acesso: leituras de diagnóstico não exigem liberação,
+    /// qualquer outro pedido exige liberação válida no escopo `DataAccess`
+    pub fn check(&self, request: &AccessRequest, now: u64) -> bool {
+        !request.requires_deliberation() || self.is_released(VetoScope::DataAccess, now)
+    }
+
+    pub fn status(&self, scope: VetoScope, now: u64) -> VetoStatus {
+        if self.is_released(scope, now) {
+            VetoStatus::ExplicitlyReleased
+        } else {
+            VetoStatus::Active
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::SigningKey;
+    use crate::gates::fixture::{identity, prince};
+
+    fn release(scope: VetoScope, nonce: u64) -> VetoToken {
+        VetoToken::sign(&prince(), VetoAction::Release, scope, nonce, 10, 20)
+    }
+
+    #[test]
+    fn accepts_a_signed_release_and_consumes_the_nonce() {
+        let mut veto = VetoControl::new(&identity());
+        assert_eq!(veto.verify_release(VetoScope::DataAccess, 15), Err(VetoError::Armed));
+        assert_eq!(veto.apply(release(VetoScope::DataAccess, 0), 15), Ok(VetoStatus::ExplicitlyReleased));
+        assert_eq!(veto.temporal_nonce(), 1);
+        assert_eq!(veto.status(VetoScope::DataAccess, 15), VetoStatus::ExplicitlyReleased);
+        // A liberação expira com a janela
+        assert_eq!(veto.verify_release(VetoScope::DataAccess, 20), Err(VetoError::Expired));
+    }
+
+    #[test]
+    fn rejects_unsigned_and_tampered_tokens() {
+        let mut veto = VetoControl::new(&identity());
+        let stranger = SigningKey::from_seed(&[9; 32]);
+        let forged = VetoToken::sign(&stranger, VetoAction::Release, VetoScope::DataAccess, 0, 10, 20);
+        assert_eq!(veto.apply(forged, 15), Err(VetoError::InvalidSignature));
+
+        let mut unsigned = release(VetoScope::DataAccess, 0);
+        unsigned.signature = [0; 64];
+        assert_eq!(veto.apply(unsigned, 15), Err(VetoError::InvalidSignature));
+
+        let mut extended = release(VetoScope::DataAccess, 0);
+        extended.expires_at = u64::MAX;
+        assert_eq!(veto.apply(extended, 15), Err(VetoError::InvalidSignature));
+        assert_eq!(veto.temporal_nonce(), 0);
+    }
+
+    #[test]
+    fn rejects_tokens_outside_their_window() {
+        let mut veto = VetoControl::new(&identity());
+        assert_eq!(veto.apply(release(VetoScope::DataAccess, 0), 9), Err(VetoError::NotYetValid));
+        assert_eq!(veto.apply(release(VetoScope::DataAccess, 0), 20), Err(VetoError::Expired));
+        assert_eq!(veto.temporal_nonce(), 0);
+    }
+
+    #[test]
+    fn rejects_replayed_and_skipped_nonces() {
+        let mut veto = VetoControl::new(&identity());
+        let token = release(VetoScope::DataAccess, 0);
+        veto.apply(token, 15).unwrap();
+        assert_eq!(veto.apply(token, 15), Err(VetoError::Replayed));
+        assert_eq!(veto.apply(release(VetoScope::DataAccess, 5), 15), Err(VetoError::NonceMismatch));
+    }
+
+    #[test]
+    fn release_is_bound_to_its_scope() {
+        let mut veto = VetoControl::new(&identity());
+        veto.apply(release(VetoScope::PhaseTransition, 0), 15).unwrap();
+        assert!(veto.is_released(VetoScope::PhaseTransition, 15));
+        assert_eq!(veto.verify_release(VetoScope::DataAccess, 15), Err(VetoError::Armed));
+        assert_eq!(veto.verify_release(VetoScope::ContainmentThaw, 15), Err(VetoError::Armed));
+
+        // Reassinar o escopo invalida a assinatura
+        let mut rescoped = release(VetoScope::PhaseTransition, 1);
+        rescoped.scope = VetoScope::DataAccess;
+        assert_eq!(veto.apply(rescoped, 15), Err(VetoError::InvalidSignature));
+    }
+
+    #[test]
+    fn arm_token_rearms_the_veto() {
+        let mut veto = VetoControl::new(&identity());
+        veto.apply(release(VetoScope::DataAccess, 0), 15).unwrap();
+        let arm = VetoToken::sign(&prince(), VetoAction::Arm, VetoScope::DataAccess, 1, 10, 20);
+        assert_eq!(veto.apply(arm, 16), Ok(VetoStatus::Active));
+        assert_eq!(veto.verify_release(VetoScope::DataAccess, 16), Err(VetoError::Armed));
+        assert_eq!(veto.status(VetoScope::DataAccess, 16), VetoStatus::Active);
+        assert_eq!(veto.temporal_nonce(), 2);
+    }
+}
@@ -0,0 +1,670 @@
+//! Deliberação do Sínodo Cardinal
+//! Membros registrados, cédulas assinadas (Ed25519) e regras de quórum configuráveis.
+//! `CardinalSynod` aplica a mesma urna às transições de fase do Gate 6.
+
+use alloc::vec::Vec;
+
+use crate::access::AccessRequest;
+use crate::crypto;
+use crate::gates::{Phase, SASCIdentity};
+use crate::merkle::{self, MerkleError, MerkleProof, MerkleTree};
+
+/// Limite fixo de membros (Power of 10, regra 2: laços com limite superior conhecido)
+pub const MAX_SYNOD_MEMBERS: usize = 64;
+
+/// Domínio de separação das mensagens de cédula
+pub const BALLOT_DOMAIN: &[u8] = b"SASC-BALLOT-v1";
+
+/// Domínio de separação da moção de transição de fase
+pub const TRANSITION_DOMAIN: &[u8] = b"SASC-TRANSITION-v1";
+
+/// Identidade de um membro: sua chave pública Ed25519
+pub type MemberId = [u8; 32];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Member {
+    pub key: MemberId,
+    pub weight: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteChoice {
+    Approve,
+    Reject,
+    Abstain,
+}
+
+impl VoteChoice {
+    fn code(self) -> u8 {
+        match self {
+            VoteChoice::Approve => 1,
+            VoteChoice::Reject => 2,
+            VoteChoice::Abstain => 3,
+        }
+    }
+}
+
+/// Regra de quórum aplicada a uma moção
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuorumRule {
+    /// Todos os membros aprovam (nenhuma abstenção, rejeição ou ausência)
+    Unanimity,
+    /// Aprovações >= numerator/denominator do número total de membros; exige
+    /// 0 < numerator <= denominator (ver `QuorumRule::validate`)
+    Supermajority { numerator: u32, denominator: u32 },
+    /// Peso aprovado >= threshold_bps (pontos-base) do peso total; 0 < threshold_bps <= 10 000
+    Weighted { threshold_bps: u32 },
+    /// Autorização permanente: aprova sem cédulas (leituras de diagnóstico)
+    Standing,
+}
+
+impl QuorumRule {
+    /// Supermaioria validada
+    pub fn supermajority(numerator: u32, denominator: u32) -> Result<Self, VoteError> {
+        let rule = QuorumRule::Supermajority { numerator, denominator };
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    /// Regra ponderada validada
+    pub fn weighted(threshold_bps: u32) -> Result<Self, VoteError> {
+        let rule = QuorumRule::Weighted { threshold_bps };
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    /// Frações nulas aprovariam sem votos e frações acima de 1 nunca aprovariam
+    pub fn validate(&self) -> Result<(), VoteError> {
+        let valid = match *self {
+            QuorumRule::Unanimity | QuorumRule::Standing => true,
+            QuorumRule::Supermajority { numerator, denominator } => numerator > 0 && numerator <= denominator,
+            QuorumRule::Weighted { threshold_bps } => threshold_bps > 0 && threshold_bps <= 10_000,
+        };
+        if valid { Ok(()) } else { Err(VoteError::InvalidRule) }
+    }
+
+    /// Decide a partir do que já foi aprovado e do máximo ainda alcançável.
+    /// Uma regra inválida (campos alterados após a construção) rejeita.
+    fn decide(&self, tally: &Tally, closed: bool) -> VoteOutcome {
+        if self.validate().is_err() {
+            return VoteOutcome::Rejected;
+        }
+        let pending = tally.members - tally.approve - tally.reject - tally.abstain;
+        let (approved, reachable) = match *self {
+            QuorumRule::Standing => return VoteOutcome::Approved,
+            _ if tally.members == 0 => return VoteOutcome::Rejected,
+            QuorumRule::Unanimity => (
+                tally.approve == tally.members,
+                tally.reject == 0 && tally.abstain == 0,
+            ),
+            QuorumRule::Supermajority { numerator, denominator } => {
+                let need = numerator as u64 * tally.members as u64;
+                let den = denominator as u64;
+                (
+                    tally.approve as u64 * den >= need,
+                    (tally.approve + pending) as u64 * den >= need,
+                )
+            }
+            QuorumRule::Weighted { threshold_bps } => {
+                let need = threshold_bps as u64 * tally.total_weight;
+                let pending_weight = tally.total_weight
+                    - tally.approve_weight
+                    - tally.reject_weight
+                    - tally.abstain_weight;
+                (
+                    tally.approve_weight > 0 && tally.approve_weight * 10_000 >= need,
+                    (tally.approve_weight + pending_weight) * 10_000 >= need,
+                )
+            }
+        };
+
+        if approved {
+            VoteOutcome::Approved
+        } else if !reachable || closed {
+            VoteOutcome::Rejected
+        } else {
+            VoteOutcome::Pending
+        }
+    }
+}
+
+/// Cédula assinada por um membro para uma moção específica
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ballot {
+    pub voter: MemberId,
+    pub motion: [u8; 32],
+    pub choice: VoteChoice,
+    pub cast_at: u64,
+    pub signature: [u8; 64],
+}
+
+impl Ballot {
+    /// Mensagem canônica assinada: domínio ‖ moção ‖ escolha ‖ instante (LE)
+    pub fn signing_message(motion: &[u8; 32], choice: VoteChoice, cast_at: u64) -> [u8; 55] {
+        let mut msg = [0u8; 55];
+        msg[..14].copy_from_slice(BALLOT_DOMAIN);
+        msg[14..46].copy_from_slice(motion);
+        msg[46] = choice.code();
+        msg[47..].copy_from_slice(&cast_at.to_le_bytes());
+        msg
+    }
+
+    pub fn sign(key: &crypto::SigningKey, motion: [u8; 32], choice: VoteChoice, cast_at: u64) -> Self {
+        let msg = Self::signing_message(&motion, choice, cast_at);
+        Self {
+            voter: key.public_key(),
+            motion,
+            choice,
+            cast_at,
+            signature: key.sign(&msg),
+        }
+    }
+
+    pub fn verify(&self) -> bool {
+        let msg = Self::signing_message(&self.motion, self.choice, self.cast_at);
+        crypto::verify(&self.voter, &msg, &self.signature)
+    }
+}
+
+/// Moção aberta à deliberação, com prazo para recebimento de cédulas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Motion {
+    pub id: [u8; 32],
+    pub deadline: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteOutcome {
+    Approved,
+    Rejected,
+    Pending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteError {
+    NotMember,
+    DuplicateBallot,
+    InvalidSignature,
+    DeadlineExpired,
+    WrongMotion,
+    NoOpenMotion,
+    RosterFull,
+    DuplicateMember,
+    /// O rol não corresponde a `SASCIdentity::cardinal_merkle`
+    RosterMismatch,
+    /// Regra de quórum com fração nula ou maior que 1
+    InvalidRule,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Tally {
+    members: u32,
+    approve: u32,
+    reject: u32,
+    abstain: u32,
+    total_weight: u64,
+    approve_weight: u64,
+    reject_weight: u64,
+    abstain_weight: u64,
+}
+
+/// Resultado apurado de uma moção
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteResult {
+    pub motion: Option<[u8; 32]>,
+    pub rule: QuorumRule,
+    pub outcome: VoteOutcome,
+    pub approve: u32,
+    pub reject: u32,
+    pub abstain: u32,
+    pub approve_weight: u64,
+    pub reject_weight: u64,
+    pub abstain_weight: u64,
+    pub total_weight: u64,
+    pub missing: Vec<MemberId>,
+}
+
+impl VoteResult {
+    /// Todos os membros registrados aprovaram
+    pub fn unanimous(&self) -> bool {
+        self.motion.is_some()
+            && self.reject == 0
+            && self.abstain == 0
+            && self.missing.is_empty()
+            && self.approve > 0
+    }
+
+    /// A regra de quórum decidiu pela aprovação
+    pub fn passed(&self) -> bool {
+        self.outcome == VoteOutcome::Approved
+    }
+}
+
+/// Sínodo Cardinal: rol de membros, regra de quórum e urna da moção corrente
+pub struct Synod {
+    /// Regra de deliberação plena (escritas, dados confidenciais)
+    pub rule: QuorumRule,
+    /// Regra para leituras de diagnóstico de baixa sensibilidade
+    pub read_rule: QuorumRule,
+    members: Vec<Member>,
+    motion: Option<Motion>,
+    ballots: Vec<Ballot>,
+}
+
+impl Synod {
+    pub fn new(rule: QuorumRule) -> Result<Self, VoteError> {
+        rule.validate()?;
+        Ok(Self::with_valid_rule(rule))
+    }
+
+    fn with_valid_rule(rule: QuorumRule) -> Self {
+        Self { rule, read_rule: QuorumRule::Standing, members: Vec::new(), motion: None, ballots: Vec::new() }
+    }
+
+    pub fn register(&mut self, member: Member) -> Result<(), VoteError> {
+        if self.members.len() >= MAX_SYNOD_MEMBERS {
+            return Err(VoteError::RosterFull);
+        }
+        if self.member(&member.key).is_some() {
+            return Err(VoteError::DuplicateMember);
+        }
+        self.members.push(member);
+        Ok(())
+    }
+
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    pub fn member(&self, key: &MemberId) -> Option<&Member> {
+        self.members.iter().find(|m| &m.key == key)
+    }
+
+    /// Abre uma nova moção, descartando as cédulas da anterior
+    pub fn open_motion(&mut self, id: [u8; 32], deadline: u64) {
+        self.motion = Some(Motion { id, deadline });
+        self.ballots.clear();
+    }
+
+    pub fn motion(&self) -> Option<&Motion> {
+        self.motion.as_ref()
+    }
+
+    /// Registra uma cédula após verificar membro, moção, prazo, duplicidade e assinatura
+    pub fn cast(&mut self, ballot: Ballot, now: u64) -> Result<(), VoteError> {
+        let motion = self.motion.ok_or(VoteError::NoOpenMotion)?;
+        if ballot.motion != motion.id {
+            return Err(VoteError::WrongMotion);
+        }
+        if now > motion.deadline || ballot.cast_at > motion.deadline {
+            return Err(VoteError::DeadlineExpired);
+        }
+        if self.member(&ballot.voter).is_none() {
+            return Err(VoteError::NotMember);
+        }
+        if self.ballots.iter().any(|b| b.voter == ballot.voter) {
+            return Err(VoteError::DuplicateBallot);
+        }
+        if !ballot.verify() {
+            return Err(VoteError::InvalidSignature);
+        }
+        self.ballots.push(ballot);
+        Ok(())
+    }
+
+    pub fn rule_for(&self, request: &AccessRequest) -> QuorumRule {
+        if request.is_diagnostic_read() { self.read_rule } else { self.rule }
+    }
+
+    /// Delibera sobre um pedido de acesso: a moção aberta deve ser o digest do pedido,
+    /// exceto quando a regra aplicável é uma autorização permanente
+    pub fn vote(&self, request: &AccessRequest, now: u64) -> VoteResult {
+        let motion = self.motion.filter(|m| m.id == request.digest());
+        self.count(motion, self.rule_for(request), now)
+    }
+
+    /// Apura a moção corrente no instante `now` sob a regra de deliberação plena
+    pub fn tally(&self, now: u64) -> VoteResult {
+        self.count(self.motion, self.rule, now)
+    }
+
+    fn count(&self, motion: Option<Motion>, rule: QuorumRule, now: u64) -> VoteResult {
+        let mut tally = Tally::default();
+        let mut missing = Vec::new();
+        let ballots: &[Ballot] = if motion.is_some() { &self.ballots } else { &[] };
+
+        for member in self.members.iter() {
+            tally.members += 1;
+            tally.total_weight += member.weight as u64;
+            match ballots.iter().find(|b| b.voter == member.key).map(|b| b.choice) {
+                Some(VoteChoice::Approve) => {
+                    tally.approve += 1;
+                    tally.approve_weight += member.weight as u64;
+                }
+                Some(VoteChoice::Reject) => {
+                    tally.reject += 1;
+                    tally.reject_weight += member.weight as u64;
+                }
+                Some(VoteChoice::Abstain) => {
+                    tally.abstain += 1;
+                    tally.abstain_weight += member.weight as u64;
+                }
+                None => missing.push(member.key),
+            }
+        }
+
+        let outcome = match (motion, rule) {
+            (_, QuorumRule::Standing) => VoteOutcome::Approved,
+            (Some(m), _) => rule.decide(&tally, now > m.deadline),
+            (None, _) => VoteOutcome::Rejected,
+        };
+
+        VoteResult {
+            motion: motion.map(|m| m.id),
+            rule,
+            outcome,
+            approve: tally.approve,
+            reject: tally.reject,
+            abstain: tally.abstain,
+            approve_weight: tally.approve_weight,
+            reject_weight: tally.reject_weight,
+            abstain_weight: tally.abstain_weight,
+            total_weight: tally.total_weight,
+            missing,
+        }
+    }
+}
+
+/// Moção de uma transição: SHA-256(domínio ‖ fase ‖ rodada LE)
+pub fn transition_motion(target: Phase, round: u64) -> [u8; 32] {
+    let mut hasher = crypto::Sha256::new();
+    hasher.update(TRANSITION_DOMAIN);
+    hasher.update(&[target.code()]);
+    hasher.update(&round.to_le_bytes());
+    hasher.finalize()
+}
+
+/// Compromisso com o rol: raiz de Merkle das chaves (`merkle::root`); chaves
+/// repetidas dão `DuplicateMember`, como no registro
+pub fn roster_commitment(keys: &[MemberId]) -> Result<[u8; 32], VoteError> {
+    merkle::root(keys).map_err(|err| match err {
+        MerkleError::DuplicateLeaf => VoteError::DuplicateMember,
+        _ => VoteError::RosterFull,
+    })
+}
+
+/// Validação de nó leve: a cédula é autêntica, refere-se à moção e o votante
+/// pertence ao rol comprometido em `root`
+pub fn verify_cardinal_ballot(
+    root: &[u8; 32],
+    motion: &[u8; 32],
+    ballot: &Ballot,
+    proof: &MerkleProof,
+) -> Result<(), VoteError> {
+    if &ballot.motion != motion {
+        return Err(VoteError::WrongMotion);
+    }
+    if !proof.verify(root, &ballot.voter) {
+        return Err(VoteError::NotMember);
+    }
+    if !ballot.verify() {
+        return Err(VoteError::InvalidSignature);
+    }
+    Ok(())
+}
+
+/// Transição em deliberação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition {
+    pub target: Phase,
+    pub round: u64,
+    pub deadline: u64,
+}
+
+/// Sínodo do Gate 6: rol de cardeais (peso 1) e cédulas assinadas sobre a
+/// transição aberta. O consenso é a fração do rol que aprovou.
+pub struct CardinalSynod {
+    synod: Synod,
+    transition: Option<Transition>,
+}
+
+impl Default for CardinalSynod {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CardinalSynod {
+    /// Rol vazio: consenso 0 até que cardeais sejam registrados
+    pub fn new() -> Self {
+        Self { synod: Synod::with_valid_rule(QuorumRule::Unanimity), transition: None }
+    }
+
+    pub fn with_roster(keys: &[MemberId]) -> Result<Self, VoteError> {
+        let mut synod = Self::new();
+        for key in keys {
+            synod.synod.register(Member { key: *key, weight: 1 })?;
+        }
+        Ok(synod)
+    }
+
+    /// Rol cujo compromisso deve coincidir com o da identidade
+    pub fn from_identity(identity: &SASCIdentity, keys: &[MemberId]) -> Result<Self, VoteError> {
+        if roster_commitment(keys)? != identity.cardinal_merkle {
+            return Err(VoteError::RosterMismatch);
+        }
+        Self::with_roster(keys)
+    }
+
+    pub fn members(&self) -> &[Member] {
+        self.synod.members()
+    }
+
+    fn keys(&self) -> Vec<MemberId> {
+        self.synod.members().iter().map(|m| m.key).collect()
+    }
+
+    pub fn roster_commitment(&self) -> Result<[u8; 32], VoteError> {
+        roster_commitment(&self.keys())
+    }
+
+    /// Prova de inclusão de um cardeal, para nós que guardam só a raiz
+    pub fn membership_proof(&self, key: &MemberId) -> Option<MerkleProof> {
+        MerkleTree::new(&self.keys()).ok()?.proof(key)
+    }
+
+    pub fn transition(&self) -> Option<Transition> {
+        self.transition
+    }
+
+    /// Abre a deliberação de uma transição, descartando cédulas anteriores
+    pub fn open_transition(&mut self, target: Phase, round: u64, deadline: u64) {
+        self.synod.open_motion(transition_motion(target, round), deadline);
+        self.transition = Some(Transition { target, round, deadline });
+    }
+
+    /// Registra uma cédula assinada sobre `transition_motion` da transição aberta;
+    /// cédulas de outra fase ou rodada têm outra moção e dão `WrongMotion`
+    pub fn cast(&mut self, ballot: Ballot, now: u64) -> Result<(), VoteError> {
+        self.transition.ok_or(VoteError::NoOpenMotion)?;
+        self.synod.cast(ballot, now)
+    }
+
+    /// Apuração da transição aberta sob unanimidade
+    pub fn tally(&self, now: u64) -> VoteResult {
+        self.synod.tally(now)
+    }
+
+    /// Fração do rol que aprovou a transição aberta (0 sem rol ou sem transição);
+    /// `cast` recusa cédulas após o prazo, então o valor não depende do instante
+    pub fn consensus(&self) -> f64 {
+        let Some(transition) = self.transition else {
+            return 0.0;
+        };
+        let members = self.synod.members().len();
+        if members == 0 {
+            return 0.0;
+        }
+        let result = self.synod.tally(transition.deadline);
+        result.approve as f64 / members as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::SigningKey;
+
+    fn keys(count: u8) -> Vec<SigningKey> {
+        (1..=count).map(|i| SigningKey::from_seed(&[i; 32])).collect()
+    }
+
+    fn synod(rule: QuorumRule, keys: &[SigningKey]) -> Synod {
+        let mut synod = Synod::new(rule).unwrap();
+        for key in keys {
+            synod.register(Member { key: key.public_key(), weight: 1 }).unwrap();
+        }
+        synod.open_motion([7; 32], 10);
+        synod
+    }
+
+    fn approve(synod: &mut Synod, key: &SigningKey) {
+        synod.cast(Ballot::sign(key, [7; 32], VoteChoice::Approve, 0), 0).unwrap();
+    }
+
+    #[test]
+    fn supermajority_is_validated_at_construction() {
+        assert_eq!(QuorumRule::supermajority(0, 3), Err(VoteError::InvalidRule));
+        assert_eq!(QuorumRule::supermajority(4, 3), Err(VoteError::InvalidRule));
+        assert_eq!(QuorumRule::supermajority(1, 0), Err(VoteError::InvalidRule));
+        assert_eq!(QuorumRule::weighted(0), Err(VoteError::InvalidRule));
+        assert_eq!(QuorumRule::weighted(10_001), Err(VoteError::InvalidRule));
+        assert!(Synod::new(QuorumRule::Supermajority { numerator: 0, denominator: 1 }).is_err());
+        assert!(QuorumRule::supermajority(3, 3).is_ok());
+    }
+
+    #[test]
+    fn supermajority_counts_against_the_whole_roster() {
+        let keys = keys(3);
+        let mut synod = synod(QuorumRule::supermajority(2, 3).unwrap(), &keys);
+        approve(&mut synod, &keys[0]);
+        assert_eq!(synod.tally(0).outcome, VoteOutcome::Pending);
+        approve(&mut synod, &keys[1]);
+        assert!(synod.tally(0).passed());
+    }
+
+    #[test]
+    fn invalid_rule_set_after_construction_rejects() {
+        let keys = keys(2);
+        let mut synod = synod(QuorumRule::Unanimity, &keys);
+        synod.rule = QuorumRule::Supermajority { numerator: 0, denominator: 1 };
+        assert_eq!(synod.tally(0).outcome, VoteOutcome::Rejected);
+    }
+
+    #[test]
+    fn ballots_are_checked_before_counting() {
+        let keys = keys(2);
+        let outsider = SigningKey::from_seed(&[99; 32]);
+        let mut synod = synod(QuorumRule::Unanimity, &keys);
+        let wrong_motion = Ballot::sign(&keys[0], [8; 32], VoteChoice::Approve, 0);
+        assert_eq!(synod.cast(wrong_motion, 0), Err(VoteError::WrongMotion));
+        let late = Ballot::sign(&keys[0], [7; 32], VoteChoice::Approve, 0);
+        assert_eq!(synod.cast(late, 11), Err(VoteError::DeadlineExpired));
+        let stranger = Ballot::sign(&outsider, [7; 32], VoteChoice::Approve, 0);
+        assert_eq!(synod.cast(stranger, 0), Err(VoteError::NotMember));
+        let mut forged = Ballot::sign(&keys[0], [7; 32], VoteChoice::Approve, 0);
+        forged.choice = VoteChoice::Reject;
+        assert_eq!(synod.cast(forged, 0), Err(VoteError::InvalidSignature));
+        approve(&mut synod, &keys[0]);
+        let again = Ballot::sign(&keys[0], [7; 32], VoteChoice::Approve, 0);
+        assert_eq!(synod.cast(again, 0), Err(VoteError::DuplicateBallot));
+        approve(&mut synod, &keys[1]);
+        assert!(synod.tally(0).unanimous());
+    }
+
+    #[test]
+    fn standing_rule_approves_diagnostic_reads_only() {
+        use crate::access::{Operations, Purpose, Sensitivity};
+        use alloc::string::String;
+        let keys = keys(2);
+        let synod = synod(QuorumRule::Unanimity, &keys);
+        let request = |operations| {
+            AccessRequest::sign(&keys[0], Purpose::Diagnostics, operations, Sensitivity::Public, String::new())
+        };
+        assert!(synod.vote(&request(Operations::READ), 0).passed());
+        assert_eq!(synod.vote(&request(Operations::WRITE), 0).outcome, VoteOutcome::Rejected);
+    }
+
+    #[test]
+    fn cardinal_synod_counts_only_the_open_transition() {
+        let cardinals = keys(2);
+        let roster: Vec<MemberId> = cardinals.iter().map(|k| k.public_key()).collect();
+        let mut synod = CardinalSynod::with_roster(&roster).unwrap();
+        assert_eq!(synod.consensus(), 0.0);
+        synod.open_transition(Phase::Superfluid, 3, 10);
+
+        let stale = Ballot::sign(&cardinals[0], transition_motion(Phase::Superfluid, 2), VoteChoice::Approve, 1);
+        assert_eq!(synod.cast(stale, 1), Err(VoteError::WrongMotion));
+
+        let motion = transition_motion(Phase::Superfluid, 3);
+        synod.cast(Ballot::sign(&cardinals[0], motion, VoteChoice::Approve, 1), 1).unwrap();
+        assert_eq!(synod.consensus(), 0.5);
+        synod.cast(Ballot::sign(&cardinals[1], motion, VoteChoice::Approve, 2), 2).unwrap();
+        assert_eq!(synod.consensus(), 1.0);
+        assert_eq!(synod.tally(2).outcome, VoteOutcome::Approved);
+    }
+
+    #[test]
+    fn light_nodes_check_ballots_against_the_root() {
+        let cardinals = keys(3);
+        let roster: Vec<MemberId> = cardinals.iter().map(|k| k.public_key()).collect();
+        let synod = CardinalSynod::with_roster(&roster).unwrap();
+        let root = synod.roster_commitment().unwrap();
+        let motion = transition_motion(Phase::Superfluid, 0);
+        let proof = synod.membership_proof(&roster[1]).unwrap();
+        let ballot = Ballot::sign(&cardinals[1], motion, VoteChoice::Approve, 0);
+        assert_eq!(verify_cardinal_ballot(&root, &motion, &ballot, &proof), Ok(()));
+
+        let other = transition_motion(Phase::Superfluid, 1);
+        assert_eq!(verify_cardinal_ballot(&root, &other, &ballot, &proof), Err(VoteError::WrongMotion));
+        let borrowed = Ballot::sign(&cardinals[0], motion, VoteChoice::Approve, 0);
+        assert_eq!(verify_cardinal_ballot(&root, &motion, &borrowed, &proof), Err(VoteError::NotMember));
+        let outsider = SigningKey::from_seed(&[99; 32]);
+        let stranger = Ballot::sign(&outsider, motion, VoteChoice::Approve, 0);
+        assert_eq!(verify_cardinal_ballot(&root, &motion, &stranger, &proof), Err(VoteError::NotMember));
+        let mut forged = ballot;
+        forged.choice = VoteChoice::Reject;
+        assert_eq!(verify_cardinal_ballot(&root, &motion, &forged, &proof), Err(VoteError::InvalidSignature));
+        let mut stale = root;
+        stale[0] ^= 1;
+        assert_eq!(verify_cardinal_ballot(&stale, &motion, &ballot, &proof), Err(VoteError::NotMember));
+    }
+
+    #[test]
+    fn roster_commitment_rejects_repeated_keys() {
+        let identity = crate::gates::fixture::identity();
+        let cardinal = crate::gates::fixture::cardinal().public_key();
+        assert_eq!(roster_commitment(&[cardinal]), Ok(identity.cardinal_merkle));
+        assert_eq!(roster_commitment(&[cardinal, cardinal]), Err(VoteError::DuplicateMember));
+        assert!(CardinalSynod::from_identity(&identity, &[cardinal]).is_ok());
+        let repeated = CardinalSynod::from_identity(&identity, &[cardinal, cardinal]);
+        assert_eq!(repeated.err(), Some(VoteError::DuplicateMember));
+        let other = SigningKey::from_seed(&[7; 32]).public_key();
+        assert_eq!(CardinalSynod::from_identity(&identity, &[other]).err(), Some(VoteError::RosterMismatch));
+    }
+
+    #[test]
+    fn late_ballots_do_not_move_the_consensus() {
+        let cardinals = keys(2);
+        let roster: Vec<MemberId> = cardinals.iter().map(|k| k.public_key()).collect();
+        let mut synod = CardinalSynod::with_roster(&roster).unwrap();
+        synod.open_transition(Phase::Superfluid, 0, 10);
+        let motion = transition_motion(Phase::Superfluid, 0);
+        synod.cast(Ballot::sign(&cardinals[0], motion, VoteChoice::Approve, 1), 1).unwrap();
+
+        let late = Ballot::sign(&cardinals[1], motion, VoteChoice::Approve, 11);
+        assert_eq!(synod.cast(late, 11), Err(VoteError::DeadlineExpired));
+        assert_eq!(synod.consensus(), 0.5);
+        assert_eq!(synod.tally(11).outcome, VoteOutcome::Rejected);
+    }
+}
@@ -0,0 +1,7 @@
+//! Garantias de tipo do crate, comparadas com `tests/ui/*.stderr`
+
+#[test]
+fn rejected_usages() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+// Compartilhar o guard entre threads exporia `&Cell` a duas threads
+use core::cell::Cell;
+use sasc::sync::{Mutex, MutexGuard};
+
+fn assert_sync<S: Sync>(_: &S) {}
+
+fn main() {
+    let mutex = Mutex::new(Cell::new(0u32));
+    let guard: MutexGuard<'_, Cell<u32>> = mutex.lock().unwrap();
+    assert_sync(&guard);
+}
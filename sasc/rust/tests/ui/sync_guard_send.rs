@@ -0,0 +1,8 @@
+// O guard restaura a prioridade da tarefa que o adquiriu: não pode migrar
+use sasc::sync::Mutex;
+
+fn main() {
+    static MUTEX: Mutex<u32> = Mutex::new(0);
+    let guard = MUTEX.lock().unwrap();
+    std::thread::spawn(move || drop(guard));
+}